@@ -1,12 +1,25 @@
 use log::info;
+use modinfo::settings::accessibility::Accessibility;
+use modinfo::settings::boss_hunt::{BossHunt, BOSS_HUNT_BOSSES};
+use modinfo::settings::compass_shuffle::CompassShuffle;
 use modinfo::settings::cracks::Cracks;
 use modinfo::settings::cracksanity::Cracksanity;
+use modinfo::settings::dungeon_entrances::DungeonEntrances;
+use modinfo::settings::fill_algorithm::FillAlgorithm;
+use modinfo::settings::interior_shuffle::InteriorShuffle;
+use modinfo::settings::keysanity::Keysanity;
 use modinfo::settings::keysy::Keysy;
+use modinfo::settings::maiamai_rewards::MaiamaiRewards;
+use modinfo::settings::maiamai_shuffle::MaiamaiShuffle;
 use modinfo::settings::nice_items::NiceItems;
 use modinfo::settings::ravios_shop::RaviosShop;
+use modinfo::settings::shop_prices::{ShopPrices, MAX_SHOP_PRICE};
+use modinfo::settings::start_location::StartLocation;
+use modinfo::settings::tower_floor_pool::TowerFloorPool;
+use modinfo::settings::traps::Trap;
 use modinfo::settings::trials_door::TrialsDoor;
 use modinfo::settings::weather_vanes::WeatherVanes;
-use modinfo::settings::{logic::LogicMode, pedestal::PedestalSetting, Settings};
+use modinfo::settings::{logic::LogicMode, pedestal::PedestalSetting, Settings, MAX_PLAYERS};
 use std::{
     io::{stdin, stdout, Read, Write},
     str::FromStr,
@@ -25,11 +38,55 @@ pub fn get_seed_settings() -> Result<Settings, String> {
 
     let logic_mode = prompt_logic_mode();
 
+    let accessibility = Accessibility::try_from(prompt_u8_in_range(
+        "Accessibility",
+        "Choose which locations must be reachable:\n\
+        [0] All Locations - Every location can be reached.\n\
+        [1] Beatable Only - Only the game is guaranteed to be beatable. Items not needed to beat it may be unreachable.",
+        0,
+        1,
+    ))?;
+
+    let fill_algorithm = FillAlgorithm::try_from(prompt_u8_in_range(
+        "Fill Algorithm",
+        "Choose how progression items are placed:\n\
+        [0] Assumed Fill          - The standard algorithm. If unsure, choose this.\n\
+        [1] Forward Fill          - Places items only where the items already placed can reach.\n\
+        [2] Progression Balancing - Assumed Fill, then moves major items out of late spheres.",
+        0,
+        2,
+    ))?;
+
+    let sphere_depth = if fill_algorithm == FillAlgorithm::ProgressionBalancing {
+        prompt_u8_in_range(
+            "Sphere Depth",
+            "Choose how many spheres Progression Balancing should aim to keep the playthrough within:",
+            1,
+            50,
+        )
+    } else {
+        12
+    };
+
+    let players = if accessibility == Accessibility::AllLocations && fill_algorithm == FillAlgorithm::AssumedFill {
+        prompt_u8_in_range(
+            "Players",
+            "Choose how many players' worlds to generate together. Items are shuffled between all of them:",
+            1,
+            MAX_PLAYERS,
+        )
+    } else {
+        1
+    };
+
     let dungeon_prize_shuffle = prompt_bool(
         "Randomize Dungeon Prizes",
         "This shuffles all Sage Portraits, Pendants, and the Charm among themselves.",
     );
 
+    // Prizes outside their prize checks can't be patched yet
+    let prizes_anywhere = false;
+
     let maiamai_limit =
         prompt_u8_in_range("Maiamai Limit", "Choose the maximum number of Maiamai you're willing to collect:", 0, 100)
             as usize;
@@ -42,14 +99,47 @@ pub fn get_seed_settings() -> Result<Settings, String> {
     );
     //let yuganon_requirement = prompt_u8_in_range("Choose how many Portraits are needed to fight Yuganon:", 0, 7);
 
-    let ped_requirement = PedestalSetting::try_from(prompt_u8_in_range(
+    let boss_hunt = BossHunt::try_from(prompt_u8_in_range(
+        "Boss Hunt",
+        "Choose whether a random set of dungeon bosses must be defeated:\n\
+        [0] Off           - No bosses are required.\n\
+        [1] Lorule Castle - Defeating the required bosses opens Lorule Castle, instead of the Portraits.\n\
+        [2] End Game      - Defeating the required bosses beats the game immediately.",
+        0,
+        2,
+    ))?;
+
+    let boss_hunt_count = if boss_hunt == BossHunt::Off {
+        10
+    } else {
+        prompt_u8_in_range("Bosses Required", "Choose how many dungeon bosses are required:", 1, BOSS_HUNT_BOSSES)
+    };
+
+    let ped_requirement = match prompt_u8_in_range(
         "Pedestal Requirement",
-        "Choose which Pendants are required to reach the Master Sword Pedestal:\n\
+        "Choose what is required to reach the Master Sword Pedestal:\n\
         [2] Vanilla  - Requires only the Pendants of Power and Wisdom.\n\
-        [3] Standard - Requires the Pendants of Power, Wisdom, and Courage.",
+        [3] Standard - Requires the Pendants of Power, Wisdom, and Courage.\n\
+        [4] Count    - Requires a chosen number of any Pendants and Sage Portraits.",
         2,
-        3,
-    ))?;
+        4,
+    ) {
+        4 => PedestalSetting::Count {
+            pendants: prompt_u8_in_range(
+                "Pedestal Pendants",
+                "Choose how many Pendants are needed:",
+                0,
+                PedestalSetting::MAX_PENDANTS,
+            ),
+            portraits: prompt_u8_in_range(
+                "Pedestal Portraits",
+                "Choose how many Sage Portraits are needed:",
+                0,
+                PedestalSetting::MAX_PORTRAITS,
+            ),
+        },
+        ped_requirement => PedestalSetting::try_from(ped_requirement)?,
+    };
 
     let nice_items = NiceItems::try_from(prompt_u8_in_range(
         "Nice Items",
@@ -80,6 +170,16 @@ pub fn get_seed_settings() -> Result<Settings, String> {
         Logic will be adjusted to require the player's items instead.",
     );
 
+    let start_location = StartLocation::try_from(prompt_u8_in_range(
+        "Start Location",
+        "Choose where Link wakes up at the start of the game:\n\
+        [0] Vanilla      - Start in Ravio's Shop.\n\
+        [1] Weather Vane - Start beside a random Weather Vane, in either world.\n\
+        [2] Random       - Start at a random safe spawn point, in either world.",
+        0,
+        2,
+    ))?;
+
     let start_with_merge =
         prompt_bool("Start with Merge", "Start with the ability to Merge into walls, without Ravio's Bracelet.");
 
@@ -104,9 +204,61 @@ pub fn get_seed_settings() -> Result<Settings, String> {
         false
     };
 
+    let shop_prices = ShopPrices::try_from(prompt_u8_in_range(
+        "Shop Prices",
+        "Choose how the items in Ravio's Shop and sold by the Street Merchant are priced:\n\
+        [0] Vanilla   - Items keep their usual prices.\n\
+        [1] Cheap     - Every item costs between 10 and 50 Rupees.\n\
+        [2] Expensive - Every item costs between 150 and 300 Rupees.\n\
+        [3] Random    - Every item costs a random price within a chosen range.",
+        0,
+        3,
+    ))?;
+
+    let (shop_price_min, shop_price_max) = if shop_prices == ShopPrices::Random {
+        let min = prompt_u16_in_range(
+            "Shop Price Minimum", "Choose the lowest price a shop item can have:", 0, MAX_SHOP_PRICE,
+        );
+        let max = prompt_u16_in_range(
+            "Shop Price Maximum", "Choose the highest price a shop item can have:", min, MAX_SHOP_PRICE,
+        );
+        (min, max)
+    } else {
+        (0, MAX_SHOP_PRICE)
+    };
+
     let maiamai_madness =
         prompt_bool("Maiamai Madness", "This shuffles Maiamai into the pool, adding 100 more locations.");
 
+    let maiamai_shuffle = if maiamai_madness {
+        match prompt_u8_in_range(
+            "Maiamai Shuffle",
+            "Choose which Maiamai are shuffled:\n\
+            [0] All    - All 100 Maiamai are shuffled.\n\
+            [1] Hyrule - Only the Maiamai found in Hyrule are shuffled.\n\
+            [2] Lorule - Only the Maiamai found in Lorule are shuffled.\n\
+            [3] Random - A random selection of Maiamai are shuffled.",
+            0,
+            3,
+        ) {
+            3 => MaiamaiShuffle::Random(prompt_u8_in_range(
+                "Random Maiamai", "Choose how many Maiamai should be shuffled:", 0, 100,
+            )),
+            maiamai_shuffle => MaiamaiShuffle::try_from(maiamai_shuffle)?,
+        }
+    } else {
+        MaiamaiShuffle::All
+    };
+
+    let maiamai_rewards = MaiamaiRewards::try_from(prompt_u8_in_range(
+        "Mother Maiamai Rewards",
+        "Choose how Mother Maiamai hands out her rewards:\n\
+        [0] Upgrades   - Rewards are tied to item upgrades and may be redeemed in any order.\n\
+        [1] Milestones - Rewards are handed out in a fixed order, one for every 10 Maiamai.",
+        0,
+        1,
+    ))?;
+
     let cracks = Cracks::try_from(prompt_u8_in_range(
         "Cracks",
         "Choose the initial state of the cracks between worlds:\n\
@@ -123,11 +275,38 @@ pub fn get_seed_settings() -> Result<Settings, String> {
         [1] Cross World Pairs          - Cracks are shuffled, but remain in Hyrule/Lorule pairs.\n\
         [2] Any World Pairs            - Cracks are shuffled freely, and can lead to the same or opposite world.\n\
         [3] Mirrored Cross World Pairs - Same as Cross World Pairs, but each pair's vanilla counterparts will be in a matching pair.\n\
-        [4] Mirrored Any World Pairs   - Same as Any World Pairs, but each pair's vanilla counterparts will be in a matching pair.",
+        [4] Mirrored Any World Pairs   - Same as Any World Pairs, but each pair's vanilla counterparts will be in a matching pair.\n\
+        [5] Decoupled                  - Cracks are shuffled freely and one-way, so returning through a crack may lead somewhere else.",
         0,
-        4,
+        5,
     ))?;
 
+    let dungeon_entrances = DungeonEntrances::try_from(prompt_u8_in_range(
+        "Dungeon Entrances",
+        "Choose how to shuffle dungeon entrances:\n\
+        [0] Vanilla      - Each entrance leads to its own dungeon.\n\
+        [1] Within World - Dungeons are shuffled amongst the entrances of their own world.\n\
+        [2] Cross World  - Dungeons are shuffled amongst the entrances of both worlds.",
+        0,
+        2,
+    ))?;
+
+    let interior_shuffle = InteriorShuffle::try_from(prompt_u8_in_range(
+        "Interior Shuffle",
+        "Choose how to shuffle Hyrule's cave, house and other interior entrances:\n\
+        [0] Off       - Every door leads to its own interior.\n\
+        [1] Coupled   - Interiors are shuffled, and leaving one returns you to the door you entered from.\n\
+        [2] Decoupled - Doors and interior exits are shuffled independently of one another.",
+        0,
+        2,
+    ))?;
+
+    let boss_shuffle = prompt_bool(
+        "Boss Shuffle",
+        "Shuffles Moldorm, Gemesaur King, Arrghus, Knucklemaster, Stalblind, Grinexx and Zaganaga amongst their arenas.\n\
+        Not yet supported by the patcher, spoiler-only.",
+    );
+
     let minigames_excluded = prompt_bool(
         "Exclude Minigames",
         "Excludes the following: Octoball Derby, Dodge the Cuccos, Hyrule Hotfoot, Treacherous Tower, and both Rupee Rushes",
@@ -177,6 +356,12 @@ pub fn get_seed_settings() -> Result<Settings, String> {
         5,
     ))?;
 
+    let decouple_weather_vanes = weather_vanes == WeatherVanes::Shuffled
+        && prompt_bool(
+            "Decoupled Weather Vanes",
+            "Each Weather Vane activates a random other Weather Vane, without being paired with it.",
+        );
+
     // let ravios_shop = RaviosShop::try_from(prompt_u8_in_range(
     //     "Ravio's Shop",
     //     "Choose whether Ravio's Shop is Closed or Open at the start of the game.\n\
@@ -215,6 +400,48 @@ pub fn get_seed_settings() -> Result<Settings, String> {
         66,
     ) as usize;
 
+    let treacherous_tower_pool = TowerFloorPool::try_from(prompt_u8_in_range(
+        "Treacherous Tower Floor Pool",
+        "Choose which floors Treacherous Tower draws its random floors from:\n\
+        [0] Mixed - Floors from every course, in a roughly even Beginner/Intermediate/Advanced split.\n\
+        [1] Easy  - Only Beginner and Intermediate floors (up to 18 floors).\n\
+        [2] Hard  - Only Advanced floors (up to 50 floors).",
+        0,
+        2,
+    ))?;
+
+    let treacherous_tower_vanilla_reward = prompt_bool(
+        "Treacherous Tower Vanilla Reward",
+        "Keep Treacherous Tower's vanilla Rupee reward instead of raising it to 1000 Rupees?",
+    );
+
+    // Other Starting Hearts values can't be patched yet
+    let starting_hearts = 3;
+
+    let heart_cap = prompt_u8_in_range(
+        "Heart Cap",
+        "What is the maximum number of Hearts Link can have?\n\
+        Heart Containers and Heart Pieces beyond this cap are replaced with junk.\nRecommended: 20",
+        starting_hearts,
+        20,
+    );
+
+    let trap_frequency = prompt_u8_in_range(
+        "Trap Frequency",
+        "What percentage of junk items should be replaced with traps? (0-100)\nRecommended: 0",
+        0,
+        100,
+    );
+
+    let trap_types = if trap_frequency > 0 {
+        Trap::ALL
+            .into_iter()
+            .filter(|trap| prompt_bool(trap.as_str(), "Allows this trap to replace junk items."))
+            .collect()
+    } else {
+        Trap::ALL.into()
+    };
+
     let purple_potion_bottles =
         prompt_bool("Purple Potion Bottles", "Fills all Empty Bottles with a free Purple Potion.");
 
@@ -229,43 +456,90 @@ pub fn get_seed_settings() -> Result<Settings, String> {
         3,
     ))?;
 
+    // Keys outside their own dungeon can't be patched yet
+    let (small_keysanity, big_keysanity) = (Keysanity::OwnDungeon, Keysanity::OwnDungeon);
+
+    let compass_shuffle = CompassShuffle::try_from(prompt_u8_in_range(
+        "Compasses",
+        "Choose where Compasses may be placed.\n\
+        [0] Vanilla     - Compasses are placed in their vanilla chests.\n\
+        [1] Own Dungeon - Compasses are placed within their own dungeon.\n",
+        0,
+        1,
+    ))?;
+
+    let informational_compasses = prompt_bool(
+        "Informational Compasses",
+        "Dungeon maps only mark the chests holding major items, so the Compass shows how many are left to find.",
+    );
+
     println!();
     info!("Starting seed generation...\n");
 
     Ok(Settings {
         dev_mode: false,
         user_exclusions: Default::default(),
+        plando: Default::default(),
         lc_requirement,
         yuganon_requirement: lc_requirement,
+        boss_hunt,
+        boss_hunt_count,
         ped_requirement,
         logic_mode,
+        accessibility,
+        fill_algorithm,
+        sphere_depth,
+        players,
         dark_rooms_lampless,
         dungeon_prize_shuffle,
+        prizes_anywhere,
         maiamai_limit,
         maiamai_madness,
+        maiamai_shuffle,
+        maiamai_rewards,
         nice_items,
         super_items,
         lamp_and_net_as_weapons,
         cracks,
         cracksanity,
+        dungeon_entrances,
+        interior_shuffle,
+        boss_shuffle,
         trials_door,
         weather_vanes,
+        decouple_weather_vanes,
         ravios_shop: RaviosShop::Open,
         bow_of_light_in_castle,
         no_progression_enemies,
         keysy,
+        small_keysanity,
+        big_keysanity,
+        compass_shuffle,
+        informational_compasses,
         progressive_bow_of_light: false,
         swordless_mode,
+        start_location,
         start_with_merge,
         start_with_pouch,
         bell_in_shop,
         sword_in_shop,
         boots_in_shop,
         assured_weapon,
+        shop_prices,
+        shop_price_min,
+        shop_price_max,
         chest_size_matches_contents,
         minigames_excluded,
         skip_big_bomb_flower,
         treacherous_tower_floors,
+        treacherous_tower_pool,
+        treacherous_tower_excluded_floors: Default::default(),
+        treacherous_tower_fixed_floors: Default::default(),
+        treacherous_tower_vanilla_reward,
+        starting_hearts,
+        heart_cap,
+        trap_frequency,
+        trap_types,
         purple_potion_bottles,
         night_mode: false,
     })
@@ -1,7 +1,7 @@
 use serde::Serialize;
-use strum::EnumIter;
+use strum::{EnumCount, EnumIter};
 
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, EnumIter, Serialize)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, EnumCount, EnumIter, Serialize)]
 pub enum HintGhost {
     // Hyrule Overworld Hint Ghosts (36) -----------------------------------------------------------
     LostWoodsMaze1,
@@ -0,0 +1,37 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// Accessibility controls which locations the filler must keep reachable.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq, Deserialize, Serialize)]
+pub enum Accessibility {
+    /// Every location must be reachable.
+    #[default]
+    AllLocations,
+    /// Only the game must be beatable. Items not needed to beat it may be placed in unreachable locations.
+    BeatableOnly,
+}
+
+impl TryFrom<u8> for Accessibility {
+    type Error = String;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::AllLocations),
+            1 => Ok(Self::BeatableOnly),
+            _ => Err("Invalid Accessibility Setting: {}".to_owned()),
+        }
+    }
+}
+
+impl Display for Accessibility {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::AllLocations => "All Locations",
+                Self::BeatableOnly => "Beatable Only",
+            }
+        )
+    }
+}
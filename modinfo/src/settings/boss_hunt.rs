@@ -0,0 +1,44 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// The number of dungeon bosses that can be required by a Boss Hunt.
+pub const BOSS_HUNT_BOSSES: u8 = 10;
+
+/// Boss Hunt requires defeating a random set of dungeon bosses, and changes what defeating all of them does.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq, Deserialize, Serialize)]
+pub enum BossHunt {
+    /// No bosses are required. Lorule Castle opens behind the usual Portrait requirement.
+    #[default]
+    Off,
+    /// Defeating the required bosses opens Lorule Castle and the fight with Yuga Ganon, instead of the Portraits.
+    LoruleCastle,
+    /// Defeating the required bosses beats the game immediately.
+    EndGame,
+}
+
+impl TryFrom<u8> for BossHunt {
+    type Error = String;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Off),
+            1 => Ok(Self::LoruleCastle),
+            2 => Ok(Self::EndGame),
+            _ => Err("Invalid BossHunt Setting: {}".to_owned()),
+        }
+    }
+}
+
+impl Display for BossHunt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Off => "Off",
+                Self::LoruleCastle => "Opens Lorule Castle",
+                Self::EndGame => "Ends Game",
+            }
+        )
+    }
+}
@@ -0,0 +1,41 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// Controls where each dungeon's Compass may be placed.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq, Deserialize, Serialize)]
+pub enum CompassShuffle {
+    /// Compasses are placed in their vanilla chests.
+    Vanilla,
+    /// Compasses are placed within their own dungeon.
+    #[default]
+    OwnDungeon,
+    /// Compasses may be placed anywhere.
+    Anywhere,
+}
+
+impl TryFrom<u8> for CompassShuffle {
+    type Error = String;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Vanilla),
+            1 => Ok(Self::OwnDungeon),
+            2 => Ok(Self::Anywhere),
+            _ => Err("Invalid CompassShuffle Setting: {}".to_owned()),
+        }
+    }
+}
+
+impl Display for CompassShuffle {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Vanilla => "Vanilla",
+                Self::OwnDungeon => "Own Dungeon",
+                Self::Anywhere => "Anywhere",
+            }
+        )
+    }
+}
@@ -15,6 +15,8 @@ pub enum Cracksanity {
     MirroredCrossWorldPairs,
     /// Same as AnyWorldPairs, but each pair's vanilla counterparts will be in a matching pair
     MirroredAnyWorldPairs,
+    /// Cracks are shuffled freely and one-way, so returning through a crack may lead somewhere else entirely
+    Decoupled,
 }
 
 impl TryFrom<u8> for Cracksanity {
@@ -27,6 +29,7 @@ impl TryFrom<u8> for Cracksanity {
             2 => Ok(Self::AnyWorldPairs),
             3 => Ok(Self::MirroredCrossWorldPairs),
             4 => Ok(Self::MirroredAnyWorldPairs),
+            5 => Ok(Self::Decoupled),
             _ => Err("Invalid CrackShuffle index: {}".to_owned()),
         }
     }
@@ -43,6 +46,7 @@ impl Display for Cracksanity {
                 Self::AnyWorldPairs => "Any World Pairs",
                 Self::MirroredCrossWorldPairs => "Mirrored Cross World Pairs",
                 Self::MirroredAnyWorldPairs => "Mirrored Any World Pairs",
+                Self::Decoupled => "Decoupled",
             }
         )
     }
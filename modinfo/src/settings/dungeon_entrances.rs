@@ -0,0 +1,41 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// Dungeon Entrance Shuffle controls which dungeon lies behind each overworld dungeon entrance.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq, Deserialize, Serialize)]
+pub enum DungeonEntrances {
+    /// Each entrance leads to its own dungeon.
+    #[default]
+    Vanilla,
+    /// Dungeons are shuffled amongst the entrances of their own world (Hyrule or Lorule).
+    WithinWorld,
+    /// Dungeons are shuffled amongst the entrances of both worlds.
+    CrossWorld,
+}
+
+impl TryFrom<u8> for DungeonEntrances {
+    type Error = String;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Vanilla),
+            1 => Ok(Self::WithinWorld),
+            2 => Ok(Self::CrossWorld),
+            _ => Err("Invalid DungeonEntrances Setting: {}".to_owned()),
+        }
+    }
+}
+
+impl Display for DungeonEntrances {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Vanilla => "Vanilla",
+                Self::WithinWorld => "Within World",
+                Self::CrossWorld => "Cross World",
+            }
+        )
+    }
+}
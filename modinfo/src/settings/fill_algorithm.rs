@@ -0,0 +1,41 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// The algorithm used to place progression items.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq, Deserialize, Serialize)]
+pub enum FillAlgorithm {
+    /// Places items backwards, assuming everything not yet placed is available.
+    #[default]
+    AssumedFill,
+    /// Places items forwards, only ever where the items already placed can reach.
+    ForwardFill,
+    /// Assumed Fill, then moves major items out of late spheres until the `sphere_depth` target is met.
+    ProgressionBalancing,
+}
+
+impl TryFrom<u8> for FillAlgorithm {
+    type Error = String;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::AssumedFill),
+            1 => Ok(Self::ForwardFill),
+            2 => Ok(Self::ProgressionBalancing),
+            _ => Err("Invalid FillAlgorithm Setting: {}".to_owned()),
+        }
+    }
+}
+
+impl Display for FillAlgorithm {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::AssumedFill => "Assumed Fill",
+                Self::ForwardFill => "Forward Fill",
+                Self::ProgressionBalancing => "Progression Balancing",
+            }
+        )
+    }
+}
@@ -0,0 +1,41 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// Interior Shuffle controls where the doors of Hyrule's caves, houses, and other interiors lead.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq, Deserialize, Serialize)]
+pub enum InteriorShuffle {
+    /// Every door leads to its own interior.
+    #[default]
+    Off,
+    /// Interiors are shuffled, and leaving an interior returns the player to the door they entered from.
+    Coupled,
+    /// Doors and interior exits are shuffled independently of one another.
+    Decoupled,
+}
+
+impl TryFrom<u8> for InteriorShuffle {
+    type Error = String;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Off),
+            1 => Ok(Self::Coupled),
+            2 => Ok(Self::Decoupled),
+            _ => Err("Invalid InteriorShuffle Setting: {}".to_owned()),
+        }
+    }
+}
+
+impl Display for InteriorShuffle {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Off => "Off",
+                Self::Coupled => "Coupled",
+                Self::Decoupled => "Decoupled",
+            }
+        )
+    }
+}
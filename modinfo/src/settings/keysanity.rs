@@ -0,0 +1,41 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// Keysanity controls where a dungeon's Keys may be placed.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq, Deserialize, Serialize)]
+pub enum Keysanity {
+    /// Keys are placed within their own dungeon.
+    #[default]
+    OwnDungeon,
+    /// Keys are placed anywhere in the same world (Hyrule or Lorule) as their dungeon, including other dungeons.
+    OwnWorld,
+    /// Keys may be placed anywhere.
+    Anywhere,
+}
+
+impl TryFrom<u8> for Keysanity {
+    type Error = String;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::OwnDungeon),
+            1 => Ok(Self::OwnWorld),
+            2 => Ok(Self::Anywhere),
+            _ => Err("Invalid Keysanity Setting: {}".to_owned()),
+        }
+    }
+}
+
+impl Display for Keysanity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::OwnDungeon => "Own Dungeon",
+                Self::OwnWorld => "Own World",
+                Self::Anywhere => "Anywhere",
+            }
+        )
+    }
+}
@@ -0,0 +1,37 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// How Mother Maiamai hands out her rewards.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq, Deserialize, Serialize)]
+pub enum MaiamaiRewards {
+    /// Rewards are tied to item upgrades, and may be redeemed in any order every 10 Maiamai.
+    #[default]
+    Upgrades,
+    /// Rewards are handed out in a fixed order, one at each 10-Maiamai milestone (10, 20, ... 90).
+    Milestones,
+}
+
+impl TryFrom<u8> for MaiamaiRewards {
+    type Error = String;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Upgrades),
+            1 => Ok(Self::Milestones),
+            _ => Err("Invalid MaiamaiRewards Setting: {}".to_owned()),
+        }
+    }
+}
+
+impl Display for MaiamaiRewards {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Upgrades => "Upgrades",
+                Self::Milestones => "Milestones",
+            }
+        )
+    }
+}
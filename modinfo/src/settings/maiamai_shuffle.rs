@@ -0,0 +1,40 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// Chooses which Maiamai become randomized checks when Maiamai Madness is enabled.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq, Deserialize, Serialize)]
+pub enum MaiamaiShuffle {
+    /// All 100 Maiamai are shuffled.
+    #[default]
+    All,
+    /// Only the Maiamai found in Hyrule are shuffled.
+    Hyrule,
+    /// Only the Maiamai found in Lorule are shuffled.
+    Lorule,
+    /// A random selection of the given number of Maiamai are shuffled.
+    Random(u8),
+}
+
+impl TryFrom<u8> for MaiamaiShuffle {
+    type Error = String;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::All),
+            1 => Ok(Self::Hyrule),
+            2 => Ok(Self::Lorule),
+            _ => Err("Invalid MaiamaiShuffle Setting: {}".to_owned()),
+        }
+    }
+}
+
+impl Display for MaiamaiShuffle {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::All => write!(f, "All"),
+            Self::Hyrule => write!(f, "Hyrule Only"),
+            Self::Lorule => write!(f, "Lorule Only"),
+            Self::Random(amount) => write!(f, "{} Random", amount),
+        }
+    }
+}
@@ -1,10 +1,24 @@
+pub use crate::settings::accessibility::Accessibility;
+pub use crate::settings::boss_hunt::BossHunt;
+pub use crate::settings::compass_shuffle::CompassShuffle;
 pub use crate::settings::cracks::Cracks;
 pub use crate::settings::cracksanity::Cracksanity;
+pub use crate::settings::dungeon_entrances::DungeonEntrances;
+pub use crate::settings::fill_algorithm::FillAlgorithm;
+pub use crate::settings::interior_shuffle::InteriorShuffle;
+pub use crate::settings::keysanity::Keysanity;
 pub use crate::settings::keysy::Keysy;
 pub use crate::settings::logic::LogicMode;
+pub use crate::settings::maiamai_rewards::MaiamaiRewards;
+pub use crate::settings::maiamai_shuffle::MaiamaiShuffle;
 pub use crate::settings::nice_items::NiceItems;
 pub use crate::settings::pedestal::PedestalSetting;
+pub use crate::settings::plando::Plando;
 pub use crate::settings::ravios_shop::RaviosShop;
+pub use crate::settings::shop_prices::ShopPrices;
+pub use crate::settings::start_location::StartLocation;
+pub use crate::settings::tower_floor_pool::TowerFloorPool;
+pub use crate::settings::traps::Trap;
 pub use crate::settings::trials_door::TrialsDoor;
 pub use crate::settings::weather_vanes::WeatherVanes;
 use log::info;
@@ -13,16 +27,33 @@ use serde::{Deserialize, Serialize};
 use std::collections::btree_set::BTreeSet;
 use std::hash::Hash;
 
+pub mod accessibility;
+pub mod boss_hunt;
+pub mod compass_shuffle;
 pub mod cracks;
 pub mod cracksanity;
+pub mod dungeon_entrances;
+pub mod fill_algorithm;
+pub mod interior_shuffle;
+pub mod keysanity;
 pub mod keysy;
 pub mod logic;
+pub mod maiamai_rewards;
+pub mod maiamai_shuffle;
 pub mod nice_items;
 pub mod pedestal;
+pub mod plando;
 pub mod ravios_shop;
+pub mod shop_prices;
+pub mod start_location;
+pub mod tower_floor_pool;
+pub mod traps;
 pub mod trials_door;
 pub mod weather_vanes;
 
+/// The most players a Multiworld seed can be generated for.
+pub const MAX_PLAYERS: u8 = 8;
+
 /// Logic and behavior settings.
 #[derive(Clone, Debug, Default, Deserialize, Hash, Serialize)]
 #[serde(default, deny_unknown_fields)]
@@ -38,7 +69,15 @@ pub struct Settings {
     #[serde(default = "seven", skip_serializing)]
     pub yuganon_requirement: u8,
 
-    /// Configure which Pendants are required to reach the Master Sword Pedestal
+    /// Boss Hunt: defeat a random set of dungeon bosses to open Lorule Castle, or to beat the game outright
+    #[serde(default)]
+    pub boss_hunt: BossHunt,
+
+    /// The number of dungeon bosses chosen to be required by the Boss Hunt
+    #[serde(default = "ten")]
+    pub boss_hunt_count: u8,
+
+    /// Configure which Pendants and/or Sage Portraits are required to reach the Master Sword Pedestal
     #[serde(default)]
     pub ped_requirement: PedestalSetting,
 
@@ -46,6 +85,22 @@ pub struct Settings {
     #[serde(default)]
     pub logic_mode: LogicMode,
 
+    /// Whether every location must be reachable, or only those needed to beat the game
+    #[serde(default)]
+    pub accessibility: Accessibility,
+
+    /// The algorithm used to place progression items
+    #[serde(default)]
+    pub fill_algorithm: FillAlgorithm,
+
+    /// The number of spheres Progression Balancing aims to keep the playthrough within
+    #[serde(default = "twelve")]
+    pub sphere_depth: u8,
+
+    /// The number of players whose worlds are generated together, with items shuffled between them
+    #[serde(default = "one")]
+    pub players: u8,
+
     /// Dark Room Lamp Requirement. If enabled, the player may have to cross dark rooms without Lamp
     #[serde(default)]
     pub dark_rooms_lampless: bool,
@@ -54,6 +109,10 @@ pub struct Settings {
     #[serde(default = "r#true")]
     pub dungeon_prize_shuffle: bool,
 
+    /// Shuffles the Pendants and Portraits into the general item pool instead of the dungeon-ending checks
+    #[serde(default)]
+    pub prizes_anywhere: bool,
+
     /// Maiamai Limit
     #[serde(default = "fifty")]
     pub maiamai_limit: usize,
@@ -62,6 +121,14 @@ pub struct Settings {
     #[serde(default)]
     pub maiamai_madness: bool,
 
+    /// Which Maiamai are shuffled when Maiamai Madness is enabled
+    #[serde(default)]
+    pub maiamai_shuffle: MaiamaiShuffle,
+
+    /// Mother Maiamai's reward behavior
+    #[serde(default)]
+    pub maiamai_rewards: MaiamaiRewards,
+
     /// Nice Items
     #[serde(default)]
     pub nice_items: NiceItems,
@@ -81,10 +148,27 @@ pub struct Settings {
     #[serde(default)]
     pub cracksanity: Cracksanity,
 
+    /// Shuffles which dungeon lies behind each overworld dungeon entrance
+    #[serde(default)]
+    pub dungeon_entrances: DungeonEntrances,
+
+    /// Shuffles where the doors of caves, houses, and other interiors lead
+    #[serde(default)]
+    pub interior_shuffle: InteriorShuffle,
+
+    /// Shuffles which boss is fought in each dungeon's boss arena
+    #[serde(default)]
+    pub boss_shuffle: bool,
+
     /// Weather Vanes behavior and activation setting.
     #[serde(default)]
     pub weather_vanes: WeatherVanes,
 
+    /// Shuffles the Weather Vanes one-way instead of in pairs, so each activates a warp to a random other Weather Vane
+    /// in either world. Requires Shuffled Weather Vanes.
+    #[serde(default)]
+    pub decouple_weather_vanes: bool,
+
     /// Ravio's Shop
     #[serde(default, skip_deserializing, skip_serializing_if = "ravios_shop_open")]
     pub ravios_shop: RaviosShop,
@@ -102,6 +186,22 @@ pub struct Settings {
     #[serde(default)]
     pub keysy: Keysy,
 
+    /// Where Small Keys may be placed (Own Dungeon, Own World, Anywhere)
+    #[serde(default)]
+    pub small_keysanity: Keysanity,
+
+    /// Where Big Keys may be placed (Own Dungeon, Own World, Anywhere)
+    #[serde(default)]
+    pub big_keysanity: Keysanity,
+
+    /// Where Compasses may be placed (Vanilla, Own Dungeon, Anywhere)
+    #[serde(default)]
+    pub compass_shuffle: CompassShuffle,
+
+    /// Informational Compasses: a dungeon's map only marks the chests holding progression items
+    #[serde(default)]
+    pub informational_compasses: bool,
+
     /// Makes the Bow of Light the third upgrade for the Bow
     #[serde(default, skip_serializing_if = "is_false")]
     pub progressive_bow_of_light: bool,
@@ -111,6 +211,10 @@ pub struct Settings {
     #[serde(default)]
     pub swordless_mode: bool,
 
+    /// Where Link wakes up at the start of the game
+    #[serde(default)]
+    pub start_location: StartLocation,
+
     /// Start with the ability to Merge into walls, without Ravio's Bracelet.
     #[serde(default)]
     pub start_with_merge: bool,
@@ -136,6 +240,18 @@ pub struct Settings {
     #[serde(default)]
     pub assured_weapon: bool,
 
+    /// How the items sold in Ravio's Shop and by the Street Merchant are priced
+    #[serde(default)]
+    pub shop_prices: ShopPrices,
+
+    /// The lowest price a shop item can be given by Random Shop Prices
+    #[serde(default)]
+    pub shop_price_min: u16,
+
+    /// The highest price a shop item can be given by Random Shop Prices
+    #[serde(default = "three_hundred")]
+    pub shop_price_max: u16,
+
     /// Alters treasure chest sizes depending on their contents: Large for Progression items, Small for everything else.
     pub chest_size_matches_contents: bool,
 
@@ -155,6 +271,38 @@ pub struct Settings {
     #[serde(default = "five")]
     pub treacherous_tower_floors: usize,
 
+    /// Which floors Treacherous Tower draws its random floors from
+    #[serde(default)]
+    pub treacherous_tower_pool: TowerFloorPool,
+
+    /// Treacherous Tower floors that are never chosen, e.g. "Advanced 12F"
+    #[serde(default, skip_serializing_if = "BTreeSet::is_empty")]
+    pub treacherous_tower_excluded_floors: BTreeSet<String>,
+
+    /// Treacherous Tower floors to play between the first floor and Moldorm, in order, instead of random ones
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub treacherous_tower_fixed_floors: Vec<String>,
+
+    /// Keep Treacherous Tower's vanilla Rupee reward instead of raising it to 1000 Rupees
+    #[serde(default)]
+    pub treacherous_tower_vanilla_reward: bool,
+
+    /// Number of Hearts the player starts the game with (1-20)
+    #[serde(default = "three")]
+    pub starting_hearts: u8,
+
+    /// Maximum number of Hearts obtainable. Surplus Heart Containers and Heart Pieces are replaced with junk.
+    #[serde(default = "twenty")]
+    pub heart_cap: u8,
+
+    /// Percentage of junk items replaced with traps (0-100)
+    #[serde(default)]
+    pub trap_frequency: u8,
+
+    /// The kinds of traps that may replace junk items
+    #[serde(default = "all_traps")]
+    pub trap_types: BTreeSet<Trap>,
+
     /// Purple Potion Bottles
     pub purple_potion_bottles: bool,
 
@@ -163,6 +311,10 @@ pub struct Settings {
 
     /// Set of user-provided locations to be excluded from having progression.
     pub user_exclusions: BTreeSet<String>,
+
+    /// Items, Cracks and Weather Vanes fixed by the user, randomizing everything else around them.
+    #[serde(skip_serializing_if = "Plando::is_empty")]
+    pub plando: Plando,
 }
 
 impl Settings {
@@ -178,19 +330,60 @@ impl Settings {
                 NoLogic => "No Logic",
             }
         );
+        info!("Accessibility:                  {}", self.accessibility);
+        if self.fill_algorithm == FillAlgorithm::ProgressionBalancing {
+            info!("Fill Algorithm:                 {} ({} Spheres)", self.fill_algorithm, self.sphere_depth);
+        } else {
+            info!("Fill Algorithm:                 {}", self.fill_algorithm);
+        }
+        if self.players > 1 {
+            info!("Players:                        {} (Multiworld)", self.players);
+        }
         info!(
             "Dungeon Prizes:                 {}",
-            if self.dungeon_prize_shuffle { "Randomized" } else { "Not Randomized" }
+            if self.prizes_anywhere {
+                "Anywhere"
+            } else if self.dungeon_prize_shuffle {
+                "Randomized"
+            } else {
+                "Not Randomized"
+            }
         );
         info!("Lorule Castle Requirement:      {} Portraits", self.lc_requirement);
         info!("Yuga Ganon Requirement:         {} Portraits", self.yuganon_requirement);
+        if self.boss_hunt != BossHunt::Off {
+            info!("Boss Hunt:                      {} ({} Bosses)", self.boss_hunt, self.boss_hunt_count);
+        }
         info!("Pedestal Requirement:           {}", self.ped_requirement);
 
         info!("Super Items:                    {}", if self.super_items { "Shuffled" } else { "Not Shuffled" });
         info!("Progression-Granting Enemies:   {}", if self.no_progression_enemies { "Removed" } else { "Vanilla" });
 
-        info!("Maiamai:                        {}", if self.maiamai_madness { "Madness" } else { "Not Randomized" });
+        if self.maiamai_madness {
+            info!("Maiamai:                        Madness ({})", self.maiamai_shuffle);
+        } else {
+            info!("Maiamai:                        Not Randomized");
+        }
+        info!("Mother Maiamai Rewards:         {}", self.maiamai_rewards);
+
+        info!("Small Keys:                     {}", self.small_keysanity);
+        info!("Big Keys:                       {}", self.big_keysanity);
+        info!(
+            "Compasses:                      {}{}",
+            self.compass_shuffle,
+            if self.informational_compasses { " (Informational)" } else { "" }
+        );
+
+        info!("Starting Hearts:                {}", self.starting_hearts);
+        info!("Heart Cap:                      {}", self.heart_cap);
+        if self.trap_frequency == 0 {
+            info!("Traps:                          Off");
+        } else {
+            let trap_types = self.trap_types.iter().map(Trap::to_string).collect::<Vec<_>>().join(", ");
+            info!("Traps:                          {}% of junk ({})", self.trap_frequency, trap_types);
+        }
 
+        info!("Start Location:                 {}", self.start_location);
         info!("Start with Merge:               {}", if self.start_with_merge { "Yes" } else { "No" });
         info!("Start with Pouch:               {}", if self.start_with_pouch { "Yes" } else { "No" });
         let shop_items = [
@@ -206,10 +399,33 @@ impl Settings {
         if !shop_items.is_empty() {
             info!("Starting Shop Items:            {}", shop_items);
         }
+        if self.shop_prices == ShopPrices::Random {
+            info!(
+                "Shop Prices:                    {} ({}-{} Rupees)",
+                self.shop_prices, self.shop_price_min, self.shop_price_max
+            );
+        } else {
+            info!("Shop Prices:                    {}", self.shop_prices);
+        }
         info!("Minigames:                      {}", if self.minigames_excluded { "Excluded" } else { "Included" });
         info!("Trials Door:                    {}", self.trials_door);
+        if self.treacherous_tower_fixed_floors.is_empty() {
+            info!(
+                "Treacherous Tower:              {} Floors ({}, {} Excluded)",
+                self.treacherous_tower_floors,
+                self.treacherous_tower_pool,
+                self.treacherous_tower_excluded_floors.len()
+            );
+        } else {
+            info!("Treacherous Tower:              {} Floors (Fixed)", self.treacherous_tower_fixed_floors.len() + 2);
+        }
+        info!(
+            "Treacherous Tower Reward:       {}",
+            if self.treacherous_tower_vanilla_reward { "Vanilla" } else { "1000 Rupees" }
+        );
         info!("Bow of Light:                   {}", if self.bow_of_light_in_castle { "Tournament" } else { "Normal" });
         info!("Weather Vanes:                  {}", self.weather_vanes);
+        info!("Decoupled Weather Vanes:        {}", if self.decouple_weather_vanes { "Yes" } else { "No" });
         info!(
             "Dark Room Crossing:             {}",
             if self.dark_rooms_lampless { "Lamp Not Required" } else { "Lamp Required" }
@@ -222,7 +438,18 @@ impl Settings {
             "Chest Size:                     {}",
             if self.chest_size_matches_contents { "Matches Contents" } else { "Normal" }
         );
-        info!("Cracksanity:                   {}", self.cracksanity)
+        info!("Cracksanity:                   {}", self.cracksanity);
+        info!("Dungeon Entrances:              {}", self.dungeon_entrances);
+        info!("Interior Shuffle:               {}", self.interior_shuffle);
+        info!("Boss Shuffle:                   {}", if self.boss_shuffle { "Shuffled" } else { "Vanilla" });
+        if !self.plando.is_empty() {
+            info!(
+                "Plando:                         {} Items, {} Crack Pairs, {} Weather Vane Pairs",
+                self.plando.items.len(),
+                self.plando.cracks.len(),
+                self.plando.weather_vanes.len()
+            );
+        }
     }
 }
 
@@ -234,6 +461,14 @@ const fn is_false(b: &bool) -> bool {
     !(*b)
 }
 
+const fn one() -> u8 {
+    1
+}
+
+const fn three() -> u8 {
+    3
+}
+
 const fn five() -> usize {
     5
 }
@@ -242,10 +477,30 @@ const fn seven() -> u8 {
     7
 }
 
+const fn ten() -> u8 {
+    10
+}
+
+const fn twelve() -> u8 {
+    12
+}
+
+const fn twenty() -> u8 {
+    20
+}
+
 const fn fifty() -> usize {
     50
 }
 
+const fn three_hundred() -> u16 {
+    300
+}
+
+fn all_traps() -> BTreeSet<Trap> {
+    Trap::ALL.into()
+}
+
 const fn r#true() -> bool {
     true
 }
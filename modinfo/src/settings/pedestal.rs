@@ -2,11 +2,41 @@ use crate::settings::pedestal::PedestalSetting::*;
 use serde::{Deserialize, Serialize};
 use std::fmt::{Display, Formatter};
 
+/// Master Sword Pedestal Requirement
 #[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq, Deserialize, Serialize)]
 pub enum PedestalSetting {
+    /// Requires the Pendants of Power and Wisdom.
     Vanilla,
+
+    /// Requires the Pendants of Power, Wisdom, and Courage.
     #[default]
     Standard,
+
+    /// Requires any number of Pendants plus any number of Sage Portraits.
+    Count { pendants: u8, portraits: u8 },
+}
+
+impl PedestalSetting {
+    pub const MAX_PENDANTS: u8 = 3;
+    pub const MAX_PORTRAITS: u8 = 7;
+
+    /// Full description of the requirement, suitable for in-game text and the spoiler log.
+    pub fn description(&self) -> String {
+        match self {
+            Vanilla => "Pendants of Power and Wisdom".to_owned(),
+            Standard => "All 3 Pendants".to_owned(),
+            Count { pendants: 0, portraits: 0 } => "Nothing".to_owned(),
+            Count { pendants, portraits: 0 } => format!("Any {}", plural(*pendants, "Pendant")),
+            Count { pendants: 0, portraits } => format!("Any {}", plural(*portraits, "Sage Portrait")),
+            Count { pendants, portraits } => {
+                format!("Any {} and {}", plural(*pendants, "Pendant"), plural(*portraits, "Sage Portrait"))
+            },
+        }
+    }
+}
+
+fn plural(amount: u8, noun: &str) -> String {
+    format!("{} {}{}", amount, noun, if amount == 1 { "" } else { "s" })
 }
 
 impl TryFrom<u8> for PedestalSetting {
@@ -23,13 +53,10 @@ impl TryFrom<u8> for PedestalSetting {
 
 impl Display for PedestalSetting {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "{}",
-            match self {
-                Vanilla => "Vanilla",
-                Standard => "Standard",
-            }
-        )
+        match self {
+            Vanilla => write!(f, "Vanilla"),
+            Standard => write!(f, "Standard"),
+            Count { .. } => write!(f, "{}", self.description()),
+        }
     }
 }
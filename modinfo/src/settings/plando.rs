@@ -0,0 +1,24 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Placements fixed ahead of time, with the randomizer filling in everything else around them.
+///
+/// Names are the same as the ones used in the spoiler log.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Plando {
+    /// Items pinned to checks, keyed by check name.
+    pub items: BTreeMap<String, String>,
+
+    /// Crack pairs, each crack leading to the other.
+    pub cracks: BTreeMap<String, String>,
+
+    /// Weather Vane pairs, each vane warping to the other.
+    pub weather_vanes: BTreeMap<String, String>,
+}
+
+impl Plando {
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty() && self.cracks.is_empty() && self.weather_vanes.is_empty()
+    }
+}
@@ -0,0 +1,49 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// The highest price a shop item can be given. Logic expects the player to afford every shop item on top of the Great
+/// Rupee Fairy, so this keeps the Rupees in the item pool enough for both.
+pub const MAX_SHOP_PRICE: u16 = 300;
+
+/// How the items sold in Ravio's Shop and by the Street Merchant are priced.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq, Deserialize, Serialize)]
+pub enum ShopPrices {
+    /// Items keep their usual prices.
+    #[default]
+    Vanilla,
+    /// Every item costs between 10 and 50 Rupees.
+    Cheap,
+    /// Every item costs between 150 and 300 Rupees.
+    Expensive,
+    /// Every item costs between `shop_price_min` and `shop_price_max` Rupees.
+    Random,
+}
+
+impl TryFrom<u8> for ShopPrices {
+    type Error = String;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Vanilla),
+            1 => Ok(Self::Cheap),
+            2 => Ok(Self::Expensive),
+            3 => Ok(Self::Random),
+            _ => Err("Invalid ShopPrices Setting: {}".to_owned()),
+        }
+    }
+}
+
+impl Display for ShopPrices {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Vanilla => "Vanilla",
+                Self::Cheap => "Cheap",
+                Self::Expensive => "Expensive",
+                Self::Random => "Random",
+            }
+        )
+    }
+}
@@ -0,0 +1,41 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// Where Link wakes up at the start of the game.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq, Deserialize, Serialize)]
+pub enum StartLocation {
+    /// Start in Ravio's Shop, as usual.
+    #[default]
+    Vanilla,
+    /// Start beside a random Weather Vane, in either world.
+    WeatherVane,
+    /// Start at a random safe spawn point, in either world.
+    Random,
+}
+
+impl TryFrom<u8> for StartLocation {
+    type Error = String;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Vanilla),
+            1 => Ok(Self::WeatherVane),
+            2 => Ok(Self::Random),
+            _ => Err("Invalid StartLocation Setting: {}".to_owned()),
+        }
+    }
+}
+
+impl Display for StartLocation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Vanilla => "Vanilla",
+                Self::WeatherVane => "Weather Vane",
+                Self::Random => "Random",
+            }
+        )
+    }
+}
@@ -0,0 +1,41 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// Which floors Treacherous Tower draws its random floors from.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq, Deserialize, Serialize)]
+pub enum TowerFloorPool {
+    /// Floors from every course, weighted towards an even Beginner/Intermediate/Advanced split.
+    #[default]
+    Mixed,
+    /// Only Beginner and Intermediate floors.
+    Easy,
+    /// Only Advanced floors.
+    Hard,
+}
+
+impl TryFrom<u8> for TowerFloorPool {
+    type Error = String;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Mixed),
+            1 => Ok(Self::Easy),
+            2 => Ok(Self::Hard),
+            _ => Err("Invalid TowerFloorPool Setting: {}".to_owned()),
+        }
+    }
+}
+
+impl Display for TowerFloorPool {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Mixed => "Mixed",
+                Self::Easy => "Easy",
+                Self::Hard => "Hard",
+            }
+        )
+    }
+}
@@ -0,0 +1,38 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// A fake item that does something unpleasant to Link when it's picked up, taking the place of a junk item.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize, Serialize)]
+pub enum Trap {
+    /// Spawns a group of enemies around Link.
+    SpawnEnemies,
+    /// Drops some of Link's Rupees on the ground around him.
+    DropRupees,
+    /// Reverses the controls for 30 seconds.
+    ReverseControls,
+    /// Forces Link into a wall merge.
+    WallMerge,
+    /// Deals one Heart of damage.
+    Damage,
+}
+
+impl Trap {
+    pub const ALL: [Self; 5] =
+        [Self::SpawnEnemies, Self::DropRupees, Self::ReverseControls, Self::WallMerge, Self::Damage];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::SpawnEnemies => "Spawn Enemies Trap",
+            Self::DropRupees => "Drop Rupees Trap",
+            Self::ReverseControls => "Reverse Controls Trap",
+            Self::WallMerge => "Wall Merge Trap",
+            Self::Damage => "Damage Trap",
+        }
+    }
+}
+
+impl Display for Trap {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
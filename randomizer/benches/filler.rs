@@ -0,0 +1,77 @@
+//! Times seed generation across a spread of settings, from the filler's point of view the easiest through to the
+//! slowest: Hell Logic with Cracksanity.
+//!
+//! Run with `cargo bench -p randomizer --bench filler`, optionally passing the number of seeds per configuration.
+
+use modinfo::settings::{Cracksanity, DungeonEntrances, InteriorShuffle, Keysanity, LogicMode, WeatherVanes};
+use modinfo::Settings;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use randomizer::{calculate_seed_info, SeedHash};
+use std::time::{Duration, Instant};
+
+const DEFAULT_SEEDS: u32 = 20;
+
+/// Settings as a preset leaving every option to its default would give.
+fn preset_defaults() -> Settings {
+    serde_json::from_str("{}").expect("Settings should deserialize from defaults")
+}
+
+fn configurations() -> Vec<(&'static str, Settings)> {
+    vec![
+        ("Normal", preset_defaults()),
+        (
+            "Hard, Cracksanity",
+            Settings {
+                logic_mode: LogicMode::Hard,
+                cracksanity: Cracksanity::AnyWorldPairs,
+                weather_vanes: WeatherVanes::Shuffled,
+                ..preset_defaults()
+            },
+        ),
+        (
+            "Glitched, Entrances",
+            Settings {
+                logic_mode: LogicMode::Glitched,
+                dungeon_entrances: DungeonEntrances::CrossWorld,
+                interior_shuffle: InteriorShuffle::Coupled,
+                small_keysanity: Keysanity::Anywhere,
+                ..preset_defaults()
+            },
+        ),
+        (
+            "Hell, Cracksanity",
+            Settings {
+                logic_mode: LogicMode::Hell,
+                cracksanity: Cracksanity::Decoupled,
+                weather_vanes: WeatherVanes::Shuffled,
+                decouple_weather_vanes: true,
+                big_keysanity: Keysanity::Anywhere,
+                ..preset_defaults()
+            },
+        ),
+    ]
+}
+
+fn main() {
+    let seeds = std::env::args().skip(1).find_map(|arg| arg.parse().ok()).unwrap_or(DEFAULT_SEEDS);
+
+    println!("{:<24} {:>12} {:>8}", "Configuration", "Per Seed", "Failed");
+    for (name, settings) in configurations() {
+        let mut elapsed = Duration::ZERO;
+        let mut failed = 0;
+
+        for seed in 0..seeds {
+            let hash = SeedHash::new(seed, &settings);
+            let rng = &mut StdRng::seed_from_u64(seed as u64);
+
+            let start = Instant::now();
+            let result = calculate_seed_info(seed, settings.clone(), hash, rng);
+            elapsed += start.elapsed();
+
+            failed += result.is_err() as u32;
+        }
+
+        println!("{:<24} {:>9.1} ms {:>8}", name, elapsed.as_secs_f64() * 1000.0 / seeds as f64, failed);
+    }
+}
@@ -0,0 +1,29 @@
+use crate::filler::filler_item::Goal;
+use modinfo::settings::boss_hunt::BossHunt;
+use modinfo::Settings;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+
+/// The dungeon bosses a Boss Hunt can require, in dungeon order.
+const BOSSES: [Goal; 10] = [
+    Goal::Yuga,
+    Goal::Margomill,
+    Goal::Moldorm,
+    Goal::GemesaurKing,
+    Goal::Arrghus,
+    Goal::Knucklemaster,
+    Goal::Stalblind,
+    Goal::Grinexx,
+    Goal::Zaganaga,
+    Goal::Dharkstare,
+];
+
+/// Chooses which bosses must be defeated to complete the Boss Hunt, if there is one.
+pub(crate) fn choose_bosses(settings: &Settings, rng: &mut StdRng) -> Vec<Goal> {
+    if settings.boss_hunt == BossHunt::Off {
+        return vec![];
+    }
+
+    let chosen = BOSSES.choose_multiple(rng, settings.boss_hunt_count as usize).collect::<Vec<_>>();
+    BOSSES.into_iter().filter(|boss| chosen.contains(&boss)).collect()
+}
@@ -0,0 +1,121 @@
+use crate::filler::util::shuffle;
+use crate::{BossMap, Result};
+use log::info;
+use modinfo::Settings;
+use rand::rngs::StdRng;
+use serde::{Serialize, Serializer};
+
+/// A dungeon boss that can be fought in another dungeon's arena.
+///
+/// Margomill, Dharkstare and both Yuga fights aren't part of the shuffle: their arenas are built around them.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Boss {
+    Moldorm,
+    GemesaurKing,
+    Arrghus,
+    Knucklemaster,
+    Stalblind,
+    Grinexx,
+    Zaganaga,
+}
+
+impl Boss {
+    pub const ALL: [Self; 7] = [
+        Self::Moldorm,
+        Self::GemesaurKing,
+        Self::Arrghus,
+        Self::Knucklemaster,
+        Self::Stalblind,
+        Self::Grinexx,
+        Self::Zaganaga,
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Moldorm => "Moldorm",
+            Self::GemesaurKing => "Gemesaur King",
+            Self::Arrghus => "Arrghus",
+            Self::Knucklemaster => "Knucklemaster",
+            Self::Stalblind => "Stalblind",
+            Self::Grinexx => "Grinexx",
+            Self::Zaganaga => "Zaganaga",
+        }
+    }
+}
+
+impl Serialize for Boss {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+/// The arena a shuffled [`Boss`] is fought in, named for the dungeon it belongs to.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum BossArena {
+    TowerOfHera,
+    DarkPalace,
+    SwampPalace,
+    SkullWoods,
+    ThievesHideout,
+    TurtleRock,
+    DesertPalace,
+}
+
+impl BossArena {
+    pub const ALL: [Self; 7] = [
+        Self::TowerOfHera,
+        Self::DarkPalace,
+        Self::SwampPalace,
+        Self::SkullWoods,
+        Self::ThievesHideout,
+        Self::TurtleRock,
+        Self::DesertPalace,
+    ];
+
+    /// The boss fought in this arena in the vanilla game.
+    pub fn get_vanilla_boss(self) -> Boss {
+        match self {
+            Self::TowerOfHera => Boss::Moldorm,
+            Self::DarkPalace => Boss::GemesaurKing,
+            Self::SwampPalace => Boss::Arrghus,
+            Self::SkullWoods => Boss::Knucklemaster,
+            Self::ThievesHideout => Boss::Stalblind,
+            Self::TurtleRock => Boss::Grinexx,
+            Self::DesertPalace => Boss::Zaganaga,
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::TowerOfHera => "Tower of Hera",
+            Self::DarkPalace => "Dark Palace",
+            Self::SwampPalace => "Swamp Palace",
+            Self::SkullWoods => "Skull Woods",
+            Self::ThievesHideout => "Thieves' Hideout",
+            Self::TurtleRock => "Turtle Rock",
+            Self::DesertPalace => "Desert Palace",
+        }
+    }
+}
+
+impl Serialize for BossArena {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+/// Builds out the BossMap, mapping each arena to the boss fought there. Empty if bosses aren't shuffled.
+pub fn build_boss_map(settings: &Settings, rng: &mut StdRng) -> Result<BossMap> {
+    if !settings.boss_shuffle {
+        return Ok(BossMap::new());
+    }
+
+    info!("Building Boss Map...");
+    Ok(BossArena::ALL.into_iter().zip(shuffle(rng, Vec::from(Boss::ALL))).collect())
+}
@@ -1,6 +1,7 @@
 use crate::filler::filler_item::Randomizable;
 use crate::filler::item_pools;
 use crate::filler::location::Location;
+use crate::filler::plando;
 use crate::Result;
 use crate::{filler, CrackMap, DashMap};
 use game::Course::{CaveDark, FieldDark, FieldLight, IndoorDark, IndoorLight};
@@ -15,9 +16,10 @@ use serde::{Serialize, Serializer};
 use std::cmp::Ordering;
 use std::fmt::{Display, Formatter};
 use std::hash::Hash;
+use strum::{EnumCount, EnumIter};
 
 /// Crack item
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, EnumCount, EnumIter)]
 pub enum Crack {
     // --- Hyrule --- //
     StylishWoman,
@@ -565,7 +567,7 @@ pub fn build_crack_map(settings: &Settings, rng: &mut StdRng) -> Result<CrackMap
     let mut hyrule_up_cracks = item_pools::get_hyrule_up_cracks();
     let mut hyrule_down_cracks = item_pools::get_hyrule_down_cracks();
     let mut lorule_up_cracks = item_pools::get_lorule_up_cracks();
-    let lorule_down_cracks = item_pools::get_lorule_down_cracks();
+    let mut lorule_down_cracks = item_pools::get_lorule_down_cracks();
 
     // Keep Desert Palace + Zaganaga Cracks vanilla (TODO new feature)
     crack_map.insert(Crack::DesertPalace, Crack::Zaganaga);
@@ -573,6 +575,16 @@ pub fn build_crack_map(settings: &Settings, rng: &mut StdRng) -> Result<CrackMap
     hyrule_up_cracks.retain(|&p| p != Crack::DesertPalace);
     lorule_up_cracks.retain(|&p| p != Crack::Zaganaga);
 
+    // Plando pairs are set aside, the rest are shuffled around them
+    for (a, b) in plando::get_crack_pins(settings)? {
+        crack_map.insert(a, b);
+        crack_map.insert(b, a);
+        for cracks in [&mut hyrule_up_cracks, &mut hyrule_down_cracks, &mut lorule_up_cracks, &mut lorule_down_cracks] {
+            cracks.retain(|&p| p != a && p != b);
+        }
+    }
+    let hc_pinned = crack_map.contains_key(&Crack::HyruleCastle);
+
     match settings.cracksanity {
         Cracksanity::Off => {
             let mut hyrule_cracks = hyrule_up_cracks;
@@ -596,11 +608,13 @@ pub fn build_crack_map(settings: &Settings, rng: &mut StdRng) -> Result<CrackMap
         },
         Cracksanity::AnyWorldPairs => {
             // Force Hyrule Castle crack to always be paired with a Lorule (Up) crack
-            let hc_match = lorule_up_cracks.remove(rng.gen_range(0..lorule_up_cracks.len()));
-            hyrule_up_cracks.retain(|&p| p != Crack::HyruleCastle);
-            lorule_up_cracks.retain(|&p| p != hc_match);
-            crack_map.insert(Crack::HyruleCastle, hc_match);
-            crack_map.insert(hc_match, Crack::HyruleCastle);
+            if !hc_pinned {
+                let hc_match = lorule_up_cracks.remove(rng.gen_range(0..lorule_up_cracks.len()));
+                hyrule_up_cracks.retain(|&p| p != Crack::HyruleCastle);
+                lorule_up_cracks.retain(|&p| p != hc_match);
+                crack_map.insert(Crack::HyruleCastle, hc_match);
+                crack_map.insert(hc_match, Crack::HyruleCastle);
+            }
 
             //
             let mut up_cracks = Vec::new();
@@ -614,6 +628,26 @@ pub fn build_crack_map(settings: &Settings, rng: &mut StdRng) -> Result<CrackMap
             crack_map.extend(filler::util::pair_randomly(rng, up_cracks)?);
             crack_map.extend(filler::util::pair_randomly(rng, down_cracks)?);
         },
+        Cracksanity::Decoupled => {
+            // The Hyrule Castle crack stays in a Lorule (Up) pair, as it must be returned through before Quake
+            if !hc_pinned {
+                let hc_match = lorule_up_cracks.remove(rng.gen_range(0..lorule_up_cracks.len()));
+                hyrule_up_cracks.retain(|&p| p != Crack::HyruleCastle);
+                crack_map.insert(Crack::HyruleCastle, hc_match);
+                crack_map.insert(hc_match, Crack::HyruleCastle);
+            }
+
+            let mut up_cracks = Vec::new();
+            up_cracks.extend(hyrule_up_cracks);
+            up_cracks.extend(lorule_up_cracks);
+
+            let mut down_cracks = Vec::new();
+            down_cracks.extend(hyrule_down_cracks);
+            down_cracks.extend(lorule_down_cracks);
+
+            crack_map.extend(filler::util::map_randomly(rng, up_cracks)?);
+            crack_map.extend(filler::util::map_randomly(rng, down_cracks)?);
+        },
         Cracksanity::MirroredCrossWorldPairs => {
             // UP
             let mut lorule_up_cracks = filler::util::shuffle(rng, lorule_up_cracks);
@@ -663,18 +697,20 @@ pub fn build_crack_map(settings: &Settings, rng: &mut StdRng) -> Result<CrackMap
         },
         Cracksanity::MirroredAnyWorldPairs => {
             // Force Hyrule Castle crack to always be paired with a Lorule (Up) crack
-            let hc_match = lorule_up_cracks.remove(rng.gen_range(0..lorule_up_cracks.len()));
-            hyrule_up_cracks.retain(|&p| p != Crack::HyruleCastle);
-            lorule_up_cracks.retain(|&p| p != hc_match);
-            crack_map.insert(Crack::HyruleCastle, hc_match);
-            crack_map.insert(hc_match, Crack::HyruleCastle);
-
-            // Mirror HC match
-            let hc_match_mirror = hc_match.get_mirror_crack();
-            lorule_up_cracks.retain(|&p| p != Crack::LoruleCastle);
-            hyrule_up_cracks.retain(|&p| p != hc_match_mirror);
-            crack_map.insert(Crack::LoruleCastle, hc_match_mirror);
-            crack_map.insert(hc_match_mirror, Crack::LoruleCastle);
+            if !hc_pinned {
+                let hc_match = lorule_up_cracks.remove(rng.gen_range(0..lorule_up_cracks.len()));
+                hyrule_up_cracks.retain(|&p| p != Crack::HyruleCastle);
+                lorule_up_cracks.retain(|&p| p != hc_match);
+                crack_map.insert(Crack::HyruleCastle, hc_match);
+                crack_map.insert(hc_match, Crack::HyruleCastle);
+
+                // Mirror HC match
+                let hc_match_mirror = hc_match.get_mirror_crack();
+                lorule_up_cracks.retain(|&p| p != Crack::LoruleCastle);
+                hyrule_up_cracks.retain(|&p| p != hc_match_mirror);
+                crack_map.insert(Crack::LoruleCastle, hc_match_mirror);
+                crack_map.insert(hc_match_mirror, Crack::LoruleCastle);
+            }
 
             // UP
             let mut up_cracks = Vec::new();
@@ -758,3 +794,42 @@ where
         crack_map.insert(vec2[i], vec1[i]);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+
+    /// Decoupled Cracks lead one-way to a different crack facing the same way, each crack being the destination of
+    /// exactly one other, while the Hyrule Castle crack keeps a two-way Lorule pair.
+    #[test]
+    fn decouples_cracks() {
+        let settings = Settings { cracksanity: Cracksanity::Decoupled, ..serde_json::from_str("{}").unwrap() };
+
+        let mut up_cracks = item_pools::get_hyrule_up_cracks();
+        up_cracks.extend(item_pools::get_lorule_up_cracks());
+        let lorule_up_cracks = item_pools::get_lorule_up_cracks();
+
+        let mut one_way = false;
+        for seed in 1..=20 {
+            let crack_map = build_crack_map(&settings, &mut StdRng::seed_from_u64(seed)).unwrap();
+
+            let mut cracks = crack_map.keys().copied().collect::<Vec<_>>();
+            let mut destinations = crack_map.values().copied().collect::<Vec<_>>();
+            cracks.sort();
+            destinations.sort();
+            assert_eq!(cracks, destinations);
+
+            for (crack, destination) in &crack_map {
+                assert_ne!(crack, destination);
+                assert_eq!(up_cracks.contains(crack), up_cracks.contains(destination));
+                one_way |= crack_map[destination] != *crack;
+            }
+
+            let hc_match = crack_map[&Crack::HyruleCastle];
+            assert!(lorule_up_cracks.contains(&hc_match));
+            assert_eq!(crack_map[&hc_match], Crack::HyruleCastle);
+        }
+        assert!(one_way);
+    }
+}
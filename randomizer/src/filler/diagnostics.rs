@@ -0,0 +1,325 @@
+use crate::filler::check::Check;
+use crate::filler::filler_item::{Item, Randomizable};
+use crate::filler::{filter_checks_by_item, get_keysanity, is_dungeon_item, is_dungeon_prize, MINIGAME_CHECKS};
+use crate::system::UserConfig;
+use crate::{CheckMap, Error, SeedInfo};
+use log::info;
+use modinfo::settings::keysanity::Keysanity;
+use path_absolutize::*;
+use serde::Serialize;
+use std::collections::{BTreeSet, HashMap};
+use std::fmt::{Display, Formatter};
+use std::fs::File;
+use std::io::Write;
+
+/// The main reason the filler ran out of checks for an item.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize)]
+pub enum FillFailureCause {
+    /// Empty checks were reachable, but the item is restricted to a dungeon, world or Prize check without any.
+    DungeonRestriction,
+    /// Reachable checks the item was allowed on were excluded, and so filled with junk.
+    Exclusions,
+    /// Every reachable check was filled by items placed before this one.
+    NoEmptyChecks,
+}
+
+impl Display for FillFailureCause {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::DungeonRestriction => "Dungeon Restriction",
+                Self::Exclusions => "Exclusions",
+                Self::NoEmptyChecks => "No Empty Checks",
+            }
+        )
+    }
+}
+
+/// An item the filler couldn't find a check for, with what the search could see when it gave up.
+#[derive(Clone, Debug, Serialize)]
+pub struct FillFailure {
+    pub item: String,
+    pub cause: FillFailureCause,
+    pub reachable_checks: usize,
+    pub empty_reachable_checks: usize,
+    /// Goals that can't be reached with every item not yet placed.
+    pub unreachable_goals: Vec<&'static str>,
+    /// Settings that kept the item off of reachable checks.
+    pub constraints: Vec<String>,
+}
+
+impl FillFailure {
+    pub(crate) fn new(
+        item: Item, reachable_checks: &[Check], all_checks: &[Check], seed_info: &SeedInfo, check_map: &CheckMap,
+    ) -> Self {
+        Self::in_worlds(item, 0, all_checks, &[(seed_info, check_map, reachable_checks)])
+    }
+
+    /// The same as [`FillFailure::new`], but for the `owner`'s item of a Multiworld seed, given what could be reached
+    /// of every world. Only the owner's world is searched for unreachable goals.
+    pub(crate) fn in_worlds(
+        item: Item, owner: usize, all_checks: &[Check], worlds: &[(&SeedInfo, &CheckMap, &[Check])],
+    ) -> Self {
+        let (owner_info, _, owner_reachable_checks) = worlds[owner];
+        let settings = &owner_info.settings;
+        let own_world_only = is_dungeon_item(item) || is_dungeon_prize(item);
+
+        let mut reachable_checks = 0;
+        let mut empty_reachable_checks = 0;
+        let (mut by_user, mut by_minigames, mut by_other_settings) = (0, 0, 0);
+        for (world, &(seed_info, check_map, checks)) in worlds.iter().enumerate() {
+            if own_world_only && world != owner {
+                continue;
+            }
+            reachable_checks += checks.len();
+            empty_reachable_checks +=
+                checks.iter().filter(|check| matches!(check_map.get(check.get_name()), Some(None))).count();
+
+            // Only excluded checks the item could otherwise have gone on kept it out
+            let excluded = checks.iter().filter(|check| seed_info.is_excluded(check.get_name())).copied().collect();
+            for check in filter_checks_by_item(item, excluded, &seed_info.settings) {
+                let name = check.get_name();
+                if seed_info.settings.user_exclusions.contains(name) {
+                    by_user += 1;
+                } else if seed_info.settings.minigames_excluded && MINIGAME_CHECKS.contains(&name) {
+                    by_minigames += 1;
+                } else {
+                    by_other_settings += 1;
+                }
+            }
+        }
+
+        let reachable_names = owner_reachable_checks.iter().map(|check| check.get_name()).collect::<BTreeSet<_>>();
+        let unreachable_goals = all_checks
+            .iter()
+            .filter(|check| !reachable_names.contains(check.get_name()))
+            .filter_map(|check| match check.get_quest() {
+                Some(Randomizable::Goal(goal)) => Some(goal.as_str()),
+                _ => None,
+            })
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect();
+
+        let mut constraints = Vec::new();
+        if is_dungeon_prize(item) && !settings.prizes_anywhere {
+            constraints.push("Dungeon Prizes can only be placed on dungeon Prize checks".to_owned());
+        } else if is_dungeon_item(item) {
+            match get_keysanity(item, settings) {
+                Keysanity::OwnDungeon => constraints.push(format!("{:?} is restricted to its own dungeon", item)),
+                Keysanity::OwnWorld => constraints.push(format!("{:?} is restricted to its dungeon's world", item)),
+                Keysanity::Anywhere => {},
+            }
+        }
+        if own_world_only && worlds.len() > 1 {
+            constraints.push(format!("{:?} is restricted to its owner's world", item));
+        }
+        for (count, setting) in [
+            (by_user, "user_exclusions"),
+            (by_minigames, "minigames_excluded"),
+            (by_other_settings, "other settings (e.g. maiamai_rewards)"),
+        ] {
+            if count > 0 {
+                constraints
+                    .push(format!("{} reachable checks the item could go on are excluded by {}", count, setting));
+            }
+        }
+
+        let cause = if by_user + by_minigames + by_other_settings > 0 {
+            FillFailureCause::Exclusions
+        } else if empty_reachable_checks > 0 {
+            FillFailureCause::DungeonRestriction
+        } else {
+            FillFailureCause::NoEmptyChecks
+        };
+
+        Self {
+            item: if worlds.len() > 1 { format!("{}'s {:?}", owner_info.player, item) } else { format!("{:?}", item) },
+            cause,
+            reachable_checks,
+            empty_reachable_checks,
+            unreachable_goals,
+            constraints,
+        }
+    }
+
+    /// An item left over once every check in the seed was filled, which no setting had any say in.
+    pub(crate) fn out_of_checks(item: String, checks: usize) -> Self {
+        Self {
+            item,
+            cause: FillFailureCause::NoEmptyChecks,
+            reachable_checks: checks,
+            empty_reachable_checks: 0,
+            unreachable_goals: vec![],
+            constraints: vec![],
+        }
+    }
+}
+
+impl Display for FillFailure {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "No reachable checks found to place: {}", self.item)
+    }
+}
+
+impl std::error::Error for FillFailure {}
+
+/// A failed attempt at generating a seed.
+#[derive(Debug, Serialize)]
+struct FailedAttempt {
+    attempt: u16,
+    seed: u32,
+    error: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    fill_failure: Option<FillFailure>,
+}
+
+/// How often something was behind a failed attempt.
+#[derive(Debug, Serialize)]
+struct Tally {
+    name: String,
+    count: usize,
+}
+
+/// Records every failed attempt of a run, to tell an unlucky preset from an impossible one.
+#[derive(Debug, Default, Serialize)]
+pub struct FillDiagnostics {
+    preset: Option<String>,
+    attempts: usize,
+    failed_attempts: Vec<FailedAttempt>,
+    common_causes: Vec<Tally>,
+    common_items: Vec<Tally>,
+}
+
+impl FillDiagnostics {
+    pub fn new(preset: Option<String>) -> Self {
+        Self { preset, ..Default::default() }
+    }
+
+    /// Records an attempt at generating a seed, and the error it failed with if any.
+    pub fn record(&mut self, attempt: u16, seed: u32, error: Option<&Error>) {
+        self.attempts += 1;
+        if let Some(error) = error {
+            self.failed_attempts.push(FailedAttempt {
+                attempt,
+                seed,
+                error: error.inner.to_string(),
+                fill_failure: error.fill_failure().cloned(),
+            });
+        }
+    }
+
+    /// Logs the most common causes of failure, then writes the full report next to the spoiler logs.
+    pub fn finish(&mut self, user_config: &UserConfig) -> crate::Result<()> {
+        let fill_failures = self.failed_attempts.iter().filter_map(|attempt| attempt.fill_failure.as_ref());
+        self.common_causes = tally(fill_failures.clone().map(|failure| failure.cause.to_string()));
+        self.common_items = tally(fill_failures.map(|failure| failure.item.clone()));
+
+        println!();
+        info!("Failed Attempts:                {} of {}", self.failed_attempts.len(), self.attempts);
+        for Tally { name, count } in &self.common_causes {
+            info!("  {: <30}{}", name, count);
+        }
+        if let Some(Tally { name, count }) = self.common_items.first() {
+            info!("Most Common Unplaced Item:      {} ({})", name, count);
+        }
+
+        let path = user_config.output().join("fill_diagnostics.json");
+        info!("Writing Fill Diagnostics to:    {}", &path.absolutize()?.display());
+        write!(File::create(path)?, "{}", serde_json::to_string_pretty(self).unwrap())?;
+
+        Ok(())
+    }
+}
+
+/// Counts each distinct name, most common first.
+fn tally(names: impl Iterator<Item = String>) -> Vec<Tally> {
+    let mut counts = HashMap::new();
+    for name in names {
+        *counts.entry(name).or_insert(0) += 1;
+    }
+    let mut tallies = counts.into_iter().map(|(name, count)| Tally { name, count }).collect::<Vec<_>>();
+    tallies.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.name.cmp(&b.name)));
+    tallies
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{calculate_seed_info, SeedHash};
+    use modinfo::settings::{Cracksanity, LogicMode, WeatherVanes};
+    use modinfo::Settings;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    /// A Sage with no Prize check left to go on is reported as such, not just as an error message.
+    #[test]
+    fn reports_fill_failure() {
+        let settings = Settings {
+            logic_mode: LogicMode::Hard,
+            cracksanity: Cracksanity::AnyWorldPairs,
+            weather_vanes: WeatherVanes::Shuffled,
+            ..serde_json::from_str("{}").unwrap()
+        };
+        let hash = SeedHash::new(2, &settings);
+        let err = calculate_seed_info(2, settings, hash, &mut StdRng::seed_from_u64(2)).unwrap_err();
+
+        let failure = err.fill_failure().expect("Seed should fail to fill");
+        assert_eq!(err.inner.to_string(), "No reachable checks found to place: SageOren");
+        assert_eq!(failure.item, "SageOren");
+        assert_eq!(failure.constraints, ["Dungeon Prizes can only be placed on dungeon Prize checks"]);
+        assert!(failure.empty_reachable_checks <= failure.reachable_checks);
+        assert_eq!(failure.cause == FillFailureCause::DungeonRestriction, failure.empty_reachable_checks > 0);
+    }
+
+    /// Excluded checks a Sage couldn't have gone on anyway aren't blamed for it not being placed.
+    #[test]
+    fn blames_only_fitting_exclusions() {
+        let settings = Settings {
+            logic_mode: LogicMode::Hard,
+            cracksanity: Cracksanity::AnyWorldPairs,
+            weather_vanes: WeatherVanes::Shuffled,
+            user_exclusions: ["Ravio's Gift", "Ravio's Shop (1)"].into_iter().map(str::to_owned).collect(),
+            ..serde_json::from_str("{}").unwrap()
+        };
+        let hash = SeedHash::new(24, &settings);
+        let err = calculate_seed_info(24, settings, hash, &mut StdRng::seed_from_u64(24)).unwrap_err();
+
+        let failure = err.fill_failure().expect("Seed should fail to fill");
+        assert_eq!(failure.item, "SageSeres");
+        assert_ne!(failure.cause, FillFailureCause::Exclusions);
+        assert_eq!(failure.constraints, ["Dungeon Prizes can only be placed on dungeon Prize checks"]);
+    }
+
+    #[test]
+    fn tallies_failed_attempts() {
+        let failure = |item: &str, cause| FillFailure {
+            item: item.to_owned(),
+            cause,
+            reachable_checks: 0,
+            empty_reachable_checks: 0,
+            unreachable_goals: vec![],
+            constraints: vec![],
+        };
+
+        let mut diagnostics = FillDiagnostics::new(Some("Test".to_owned()));
+        diagnostics.record(1, 10, None);
+        diagnostics.record(2, 11, Some(&Error::game(failure("SageOren", FillFailureCause::DungeonRestriction))));
+        diagnostics.record(3, 12, Some(&Error::game(failure("Bow01", FillFailureCause::NoEmptyChecks))));
+        diagnostics.record(4, 13, Some(&Error::game(failure("Bow01", FillFailureCause::NoEmptyChecks))));
+        diagnostics.record(5, 14, Some(&Error::game("Only 509/510 checks were reachable in the world graph")));
+
+        assert_eq!(diagnostics.attempts, 5);
+        assert_eq!(diagnostics.failed_attempts.len(), 4);
+        assert!(diagnostics.failed_attempts[3].fill_failure.is_none());
+
+        let fill_failures = diagnostics.failed_attempts.iter().filter_map(|attempt| attempt.fill_failure.as_ref());
+        let items = tally(fill_failures.map(|failure| failure.item.clone()));
+        assert_eq!(
+            items.iter().map(|tally| (tally.name.as_str(), tally.count)).collect::<Vec<_>>(),
+            [("Bow01", 2), ("SageOren", 1)]
+        );
+    }
+}
@@ -0,0 +1,231 @@
+use crate::filler::loading_zone_pair::{LoadingZoneId, LoadingZones};
+use crate::filler::location::Location;
+use crate::filler::util::shuffle;
+use crate::{DungeonEntranceMap, Result};
+use game::Course::{
+    self, DungeonDark, DungeonDokuro, DungeonEast, DungeonHera, DungeonIce, DungeonKame, DungeonSand, DungeonWind,
+    FieldDark, FieldLight,
+};
+use log::info;
+use modinfo::settings::DungeonEntrances;
+use modinfo::Settings;
+use rand::rngs::StdRng;
+use serde::{Serialize, Serializer};
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+
+/// Dungeon Entrance
+///
+/// Each variant names both an overworld entrance and the dungeon found behind it in the vanilla game. Swamp Palace,
+/// Thieves' Hideout, and both Castles are not part of the shuffle: their entrances don't lead into a foyer that can
+/// be separated from the surrounding overworld logic.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum DungeonEntrance {
+    // --- Hyrule --- //
+    EasternPalace,
+    HouseOfGales,
+    TowerOfHera,
+    DesertPalace,
+
+    // --- Lorule --- //
+    DarkPalace,
+    SkullWoods,
+    IceRuins,
+    TurtleRock,
+}
+
+impl DungeonEntrance {
+    pub const ALL: [Self; 8] = [
+        Self::EasternPalace,
+        Self::HouseOfGales,
+        Self::TowerOfHera,
+        Self::DesertPalace,
+        Self::DarkPalace,
+        Self::SkullWoods,
+        Self::IceRuins,
+        Self::TurtleRock,
+    ];
+
+    pub fn get_world(self) -> game::World {
+        match self {
+            Self::EasternPalace | Self::HouseOfGales | Self::TowerOfHera | Self::DesertPalace => game::World::Hyrule,
+            Self::DarkPalace | Self::SkullWoods | Self::IceRuins | Self::TurtleRock => game::World::Lorule,
+        }
+    }
+
+    /// The overworld Location holding the path into this entrance.
+    pub(crate) fn get_outside_location(self) -> Location {
+        match self {
+            Self::EasternPalace => Location::EasternRuinsUpper,
+            Self::HouseOfGales => Location::HouseOfGalesIsland,
+            Self::TowerOfHera => Location::DeathMountainWestTop,
+            Self::DesertPalace => Location::DesertPalaceWeatherVane,
+            Self::DarkPalace => Location::DarkPalaceWeatherVane,
+            Self::SkullWoods => Location::SkullWoodsOverworld,
+            Self::IceRuins => Location::LoruleDeathEastTop,
+            Self::TurtleRock => Location::TurtleRockFrontDoor,
+        }
+    }
+
+    /// The overworld Location the player is returned to when leaving through this entrance.
+    pub(crate) fn get_exit_location(self) -> Location {
+        match self {
+            Self::DarkPalace => Location::DarkRuins,
+            _ => self.get_outside_location(),
+        }
+    }
+
+    /// The first Location inside this dungeon.
+    pub(crate) fn get_foyer_location(self) -> Location {
+        match self {
+            Self::EasternPalace => Location::EasternPalaceFoyer,
+            Self::HouseOfGales => Location::HouseOfGalesFoyer,
+            Self::TowerOfHera => Location::TowerOfHeraFoyer,
+            Self::DesertPalace => Location::DesertPalaceFoyer,
+            Self::DarkPalace => Location::DarkPalaceFoyer,
+            Self::SkullWoods => Location::SkullWoodsFoyer,
+            Self::IceRuins => Location::IceRuinsFoyer,
+            Self::TurtleRock => Location::TurtleRockFoyer,
+        }
+    }
+
+    /// The overworld scene containing this entrance's loading zone.
+    pub(crate) fn get_overworld_scene(self) -> (Course, u16) {
+        match self {
+            Self::EasternPalace => (FieldLight, 20),
+            Self::HouseOfGales => (FieldLight, 35),
+            Self::TowerOfHera => (FieldLight, 3),
+            Self::DesertPalace => (FieldLight, 31),
+            Self::DarkPalace => (FieldDark, 20),
+            Self::SkullWoods => (FieldDark, 1),
+            Self::IceRuins => (FieldDark, 5),
+            Self::TurtleRock => (FieldDark, 35),
+        }
+    }
+
+    /// The dungeon scene containing this dungeon's main exit.
+    pub(crate) fn get_dungeon_scene(self) -> (Course, u16) {
+        match self {
+            Self::EasternPalace => (DungeonEast, 1),
+            Self::HouseOfGales => (DungeonWind, 1),
+            Self::TowerOfHera => (DungeonHera, 1),
+            Self::DesertPalace => (DungeonSand, 1),
+            Self::DarkPalace => (DungeonDark, 1),
+            Self::SkullWoods => (DungeonDokuro, 1),
+            Self::IceRuins => (DungeonIce, 1),
+            Self::TurtleRock => (DungeonKame, 1),
+        }
+    }
+
+    /// The overworld spawn point outside this entrance, if it's already recorded in [`LoadingZones`].
+    pub(crate) fn get_known_exit_spawn(self) -> Option<i32> {
+        let id = match self {
+            Self::EasternPalace => LoadingZoneId::EASTERN_PALACE,
+            Self::TowerOfHera => LoadingZoneId::TOWER_OF_HERA,
+            Self::DesertPalace => LoadingZoneId::DESERT_PALACE_MAIN,
+            _ => return None,
+        };
+        let (entrances, _) = LoadingZones::dungeon_entrances();
+        entrances.get(&id).map(|zone| zone.get_spawn() as i32)
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::EasternPalace => "Eastern Palace",
+            Self::HouseOfGales => "House of Gales",
+            Self::TowerOfHera => "Tower of Hera",
+            Self::DesertPalace => "Desert Palace",
+            Self::DarkPalace => "Dark Palace",
+            Self::SkullWoods => "Skull Woods",
+            Self::IceRuins => "Ice Ruins",
+            Self::TurtleRock => "Turtle Rock",
+        }
+    }
+}
+
+impl Display for DungeonEntrance {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl Ord for DungeonEntrance {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+impl PartialOrd<DungeonEntrance> for DungeonEntrance {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Serialize for DungeonEntrance {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+/// Builds out the DungeonEntranceMap, mapping each overworld entrance to the dungeon found behind it.
+pub fn build_dungeon_entrance_map(settings: &Settings, rng: &mut StdRng) -> Result<DungeonEntranceMap> {
+    info!("Building Dungeon Entrance Map...");
+
+    let entrances = Vec::from(DungeonEntrance::ALL);
+    let dungeons = match settings.dungeon_entrances {
+        DungeonEntrances::Vanilla => entrances.clone(),
+        DungeonEntrances::WithinWorld => {
+            let (hyrule, lorule): (Vec<_>, Vec<_>) =
+                entrances.iter().partition(|entrance| entrance.get_world() == game::World::Hyrule);
+            let mut dungeons = shuffle(rng, hyrule);
+            dungeons.extend(shuffle(rng, lorule));
+            dungeons
+        },
+        DungeonEntrances::CrossWorld => shuffle(rng, entrances.clone()),
+    };
+
+    Ok(entrances.into_iter().zip(dungeons).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+
+    fn build(dungeon_entrances: DungeonEntrances, seed: u64) -> DungeonEntranceMap {
+        let settings = Settings { dungeon_entrances, ..serde_json::from_str("{}").unwrap() };
+        build_dungeon_entrance_map(&settings, &mut StdRng::seed_from_u64(seed)).unwrap()
+    }
+
+    /// Every entrance leads to exactly one dungeon, staying in its own world unless the shuffle is Cross World.
+    #[test]
+    fn maps_each_entrance_to_one_dungeon() {
+        assert!(build(DungeonEntrances::Vanilla, 1).iter().all(|(entrance, dungeon)| entrance == dungeon));
+
+        let mut all = Vec::from(DungeonEntrance::ALL);
+        all.sort();
+
+        let mut crossed_worlds = false;
+        for seed in 1..=20 {
+            for setting in [DungeonEntrances::WithinWorld, DungeonEntrances::CrossWorld] {
+                let map = build(setting, seed);
+                assert_eq!(map.keys().copied().collect::<Vec<_>>(), all);
+
+                let mut dungeons = map.values().copied().collect::<Vec<_>>();
+                dungeons.sort();
+                assert_eq!(dungeons, all);
+
+                let crosses = map.iter().any(|(entrance, dungeon)| entrance.get_world() != dungeon.get_world());
+                if setting == DungeonEntrances::WithinWorld {
+                    assert!(!crosses);
+                } else {
+                    crossed_worlds |= crosses;
+                }
+            }
+        }
+        assert!(crossed_worlds);
+    }
+}
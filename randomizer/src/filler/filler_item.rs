@@ -1,11 +1,14 @@
 use crate::filler::cracks::Crack;
 use crate::hints::{hint_color::HintColor::*, hint_ghost_name};
 use crate::patch::lms::msbf::MsbfKey;
-use crate::Result;
+use crate::patch::util::{remote_item_slot, REMOTE_ITEMS};
+use crate::{PlayerId, Result};
 use game::ghosts::HintGhost;
+use modinfo::settings::traps::Trap;
 use rom::flag::Flag;
 use serde::{Serialize, Serializer};
-use std::fmt::Debug;
+use std::fmt::{Debug, Display, Formatter};
+use strum::{EnumCount, EnumIter};
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum Randomizable {
@@ -14,6 +17,10 @@ pub enum Randomizable {
     HintGhost(HintGhost),
     Vane(Vane),
     Crack(Crack),
+    /// An item placed in this world for another player of a Multiworld seed.
+    Remote(PlayerId, Item),
+    /// A trap taking the place of a junk item.
+    Trap(Trap),
 }
 
 impl Randomizable {
@@ -24,7 +31,8 @@ impl Randomizable {
         }
     }
 
-    pub fn normalize(self) -> game::Item {
+    /// The get-item this is given as in the given player's world.
+    pub fn normalize(self, world: PlayerId) -> game::Item {
         use game::Item::*;
         use Randomizable::*;
         match self {
@@ -46,12 +54,13 @@ impl Randomizable {
                 ItemInsectNetLv2 => ItemInsectNet,
                 item => item,
             },
+            Remote(recipient, _) => REMOTE_ITEMS[remote_item_slot(world, recipient)].0,
             _ => unreachable!(),
         }
     }
 
-    pub fn as_item_index(&self) -> u32 {
-        self.normalize() as u32
+    pub fn as_item_index(&self, world: PlayerId) -> u32 {
+        self.normalize(world) as u32
     }
 
     pub fn is_hint_ghost(self) -> bool {
@@ -192,6 +201,9 @@ impl Randomizable {
 
     pub fn is_major_item(&self) -> bool {
         use crate::filler::filler_item::Item::*;
+        if let Randomizable::Remote(_, item) = self {
+            return Randomizable::Item(*item).is_major_item();
+        }
         matches!(
             self,
             Randomizable::Item(
@@ -345,12 +357,23 @@ impl Randomizable {
             Self::HintGhost(ghost) => hint_ghost_name(&ghost),
             Self::Vane(vane) => vane.as_str(),
             Self::Crack(crack) => crack.as_str(),
+            Self::Remote(_, item) => item.as_str(),
+            Self::Trap(trap) => trap.as_str(),
+        }
+    }
+
+    pub fn get_article(self) -> &'static str {
+        match self {
+            Self::Item(item) => item.get_article(),
+            Self::Trap(_) => "a",
+            _ => "",
         }
     }
 
     pub fn as_str_colorized(&self) -> String {
         match self {
             Self::Goal(goal) => goal.as_str_colorized(),
+            Self::Remote(player, item) => format!("{}'s {}", player, Name.format(item.as_str())),
             _ => Name.format(self.as_str()),
         }
     }
@@ -386,16 +409,31 @@ impl From<Crack> for Randomizable {
     }
 }
 
+impl From<Trap> for Randomizable {
+    fn from(trap: Trap) -> Self {
+        Self::Trap(trap)
+    }
+}
+
 impl Serialize for Randomizable {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        serializer.serialize_str(self.as_str())
+        serializer.collect_str(self)
+    }
+}
+
+impl Display for Randomizable {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Remote(player, item) => write!(f, "{}'s {}", player, item.as_str()),
+            _ => write!(f, "{}", self.as_str()),
+        }
     }
 }
 
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Ord, PartialOrd)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Ord, PartialOrd, EnumCount, EnumIter)]
 pub enum Item {
     Empty,
 
@@ -1296,7 +1334,7 @@ impl Serialize for Item {
 }
 
 // Quest Items ---------------------------------------------------------------------------------
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, EnumCount, EnumIter)]
 pub enum Goal {
     // Bosses -------
     Yuga,
@@ -1409,7 +1447,7 @@ impl Serialize for Goal {
 }
 
 /// Weather Vane Item
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Ord, PartialOrd)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Ord, PartialOrd, EnumCount, EnumIter)]
 pub enum Vane {
     BlacksmithWV,
     DarkPalaceWV,
@@ -0,0 +1,110 @@
+use crate::filler::check::Check;
+use crate::filler::diagnostics::FillFailure;
+use crate::filler::item_pools::Pool;
+use crate::filler::progress::Progress;
+use crate::filler::reachability::Reachability;
+use crate::filler::{filter_checks, get_all_checks, handle_maiamai_limit, place_item_randomly};
+use crate::{CheckMap, SeedInfo};
+use log::{debug, info};
+use rand::rngs::StdRng;
+
+const MAX_ATTEMPTS: usize = 50;
+
+/// The Forward Fill algorithm
+///
+/// Places `items_owned` one at a time, each onto a random empty check that the items already placed can reach. Items
+/// are only ever placed with what comes before them in mind, so progression tends to be front-loaded.
+///
+/// Forward Fill can paint itself into a corner by filling every reachable check before anything opening up more of the
+/// world is placed. Each attempt starts over from the given `check_map`, up to [`MAX_ATTEMPTS`] times.
+pub(super) fn forward_fill(
+    rng: &mut StdRng, seed_info: &SeedInfo, check_map: &mut CheckMap, items_owned: &mut Pool, junk: &mut Pool,
+) -> crate::Result<()> {
+    info!("Placing Progression Items...");
+
+    let all_checks = get_all_checks(seed_info);
+    let mut last_failure = None;
+
+    for attempt in 1..=MAX_ATTEMPTS {
+        let mut attempt_check_map = check_map.clone();
+        let mut attempt_items = items_owned.clone();
+        let mut attempt_junk = junk.clone();
+
+        match try_forward_fill(
+            rng, seed_info, &mut attempt_check_map, &mut attempt_items, &mut attempt_junk, &all_checks,
+        ) {
+            Ok(()) => {
+                *check_map = attempt_check_map;
+                *items_owned = attempt_items;
+                *junk = attempt_junk;
+                return Ok(());
+            },
+            Err(failure) => {
+                debug!("Forward Fill attempt #{} ran out of reachable checks for {}", attempt, failure.item);
+                last_failure = Some(failure);
+            },
+        }
+    }
+
+    info!("Forward Fill gave up after {} attempts", MAX_ATTEMPTS);
+    Err(crate::Error::game(last_failure.unwrap()))
+}
+
+/// Runs a single attempt at Forward Fill, failing with the next item in the pool if nothing left can be placed.
+fn try_forward_fill(
+    rng: &mut StdRng, seed_info: &SeedInfo, check_map: &mut CheckMap, items_owned: &mut Pool, junk: &mut Pool,
+    all_checks: &[Check],
+) -> Result<(), FillFailure> {
+    let mut reachability = Reachability::new(Progress::new(seed_info));
+    let mut major_maiamai_items = 0;
+
+    while !items_owned.is_empty() {
+        reachability.collect_placed_items(seed_info, check_map);
+        reachability.expand(seed_info, check_map, |_| false);
+        let reachable_checks = reachability.reachable_checks(seed_info);
+
+        let Some((index, checks)) = choose_item(seed_info, check_map, items_owned, &reachability, &reachable_checks)
+        else {
+            return Err(FillFailure::new(items_owned[0], &reachable_checks, all_checks, seed_info, check_map));
+        };
+
+        let item = items_owned.remove(index);
+        let chosen_check_name = place_item_randomly(item, &checks, check_map, rng);
+        handle_maiamai_limit(item.into(), chosen_check_name, &mut major_maiamai_items, seed_info, check_map, junk);
+    }
+
+    Ok(())
+}
+
+/// Chooses the next item to place, and the checks it can go on: the first item in the pool with somewhere reachable to
+/// go. Once a single reachable check is left open, the item placed on it must open up more of the world.
+fn choose_item(
+    seed_info: &SeedInfo, check_map: &mut CheckMap, items_owned: &Pool, reachability: &Reachability,
+    reachable_checks: &[Check],
+) -> Option<(usize, Vec<Check>)> {
+    let open_checks = count_open_checks(reachable_checks, check_map);
+
+    for (index, &item) in items_owned.iter().enumerate() {
+        let checks = filter_checks(item, reachable_checks, seed_info, check_map);
+        if checks.is_empty() {
+            continue;
+        }
+
+        if open_checks <= 1 {
+            let mut with_item = reachability.clone();
+            with_item.add_item(item);
+            with_item.expand(seed_info, check_map, |_| false);
+            if count_open_checks(&with_item.reachable_checks(seed_info), check_map) <= open_checks {
+                continue;
+            }
+        }
+
+        return Some((index, checks));
+    }
+
+    None
+}
+
+fn count_open_checks(checks: &[Check], check_map: &CheckMap) -> usize {
+    checks.iter().filter(|check| check_map.get(check.get_name()).unwrap().is_none()).count()
+}
@@ -0,0 +1,265 @@
+use crate::filler::loading_zone_pair::{LoadingZone, LoadingZoneId, LoadingZones};
+use crate::filler::location::Location;
+use crate::filler::util::shuffle;
+use crate::world::WorldGraph;
+use crate::{filler, world, Error, Result, SeedInfo};
+use log::info;
+use modinfo::settings::InteriorShuffle;
+use rand::{rngs::StdRng, Rng};
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+/// Interior Map
+///
+/// Doors map each overworld door to the interior the player enters through it, while exits map each interior exit to
+/// the overworld door the player leaves through. When Interior Shuffle is Coupled the exits are the inverse of the doors.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct InteriorMap {
+    doors: BTreeMap<LoadingZoneId, LoadingZoneId>,
+    exits: BTreeMap<LoadingZoneId, LoadingZoneId>,
+}
+
+impl InteriorMap {
+    /// Doors leading somewhere other than their vanilla interior.
+    pub(crate) fn shuffled_doors(&self) -> impl Iterator<Item = (LoadingZoneId, LoadingZoneId)> + '_ {
+        self.doors.iter().filter(|(door, interior)| door != interior).map(|(&door, &interior)| (door, interior))
+    }
+
+    /// Interior exits leading somewhere other than their vanilla door.
+    pub(crate) fn shuffled_exits(&self) -> impl Iterator<Item = (LoadingZoneId, LoadingZoneId)> + '_ {
+        self.exits.iter().filter(|(interior, door)| interior != door).map(|(&interior, &door)| (interior, door))
+    }
+
+    /// Graph redirects, as `(node, old destination, new destination)`, placing each interior behind its shuffled door.
+    pub(crate) fn get_redirects(&self) -> Vec<(Location, Location, Location)> {
+        let (doors, exits) = get_interior_zones();
+        let mut redirects = Vec::new();
+
+        for (door, interior) in self.shuffled_doors() {
+            redirects.push((doors[&door].get_location(), exits[&door].get_location(), exits[&interior].get_location()));
+        }
+
+        for (interior, door) in self.shuffled_exits() {
+            redirects.push((
+                exits[&interior].get_location(),
+                doors[&interior].get_location(),
+                doors[&door].get_location(),
+            ));
+        }
+
+        redirects
+    }
+}
+
+/// All overworld doors and interior exits that take part in Interior Shuffle. Only Hyrule's interiors are shuffled,
+/// as the door and spawn IDs of Lorule's haven't been mapped yet.
+pub(crate) fn get_interior_zones() -> (HashMap<LoadingZoneId, LoadingZone>, HashMap<LoadingZoneId, LoadingZone>) {
+    LoadingZones::hyrule_entrances()
+}
+
+/// Shuffles the interiors found behind each overworld door, rebuilding the World Graph to match.
+///
+/// Several interiors are the only connection to the region beyond them (e.g. Death Mountain), so the layout is built
+/// one door at a time from the start: each door or interior exit is only assigned once it can be reached with all
+/// possible progression, and only to a destination that leaves another unassigned door or exit reachable.
+pub fn shuffle_interiors(rng: &mut StdRng, seed_info: &mut SeedInfo) -> Result<()> {
+    if seed_info.settings.interior_shuffle == InteriorShuffle::Off {
+        return Ok(());
+    }
+
+    info!("Shuffling Interiors...");
+
+    let interior_map = build_interior_map(rng, seed_info);
+    seed_info.world_graph =
+        world::build_world_graph(&seed_info.crack_map, &seed_info.dungeon_entrance_map, &InteriorMap::default());
+    let interior_map = interior_map?;
+
+    let logic_mode = seed_info.settings.logic_mode;
+    let vanilla_locations = seed_info.world_graph.find_connected_locations(seed_info.start.get_location(), logic_mode);
+    let world_graph = world::build_world_graph(&seed_info.crack_map, &seed_info.dungeon_entrance_map, &interior_map);
+    if !world_graph.find_connected_locations(seed_info.start.get_location(), logic_mode).is_superset(&vanilla_locations)
+    {
+        return Err(Error::game("Interior Shuffle layout disconnected part of the world"));
+    }
+
+    let vanilla_graph = std::mem::replace(&mut seed_info.world_graph, world_graph);
+    seed_info.interior_map = interior_map;
+    if !filler::all_locations_accessible(rng, seed_info) {
+        seed_info.world_graph = vanilla_graph;
+        seed_info.interior_map = InteriorMap::default();
+        return Err(Error::game("Interior Shuffle layout left some locations inaccessible"));
+    }
+
+    Ok(())
+}
+
+/// A door or interior exit waiting to be assigned a destination.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+enum Pending {
+    Door(LoadingZoneId),
+    Exit(LoadingZoneId),
+}
+
+/// Builds the Interior Map outward from the start, searching partial World Graphs in place of `seed_info`'s own.
+/// See [`shuffle_interiors`].
+fn build_interior_map(rng: &mut StdRng, seed_info: &mut SeedInfo) -> Result<InteriorMap> {
+    let (doors, exits) = get_interior_zones();
+    let mut ids = doors.keys().copied().collect::<Vec<_>>();
+    ids.sort();
+
+    let coupled = seed_info.settings.interior_shuffle != InteriorShuffle::Decoupled;
+    let vanilla_paths = get_vanilla_paths(seed_info, &ids, &doors, &exits);
+    let (check_map, progression_pool) = filler::assumed_progression(rng, seed_info);
+    let find_reachable = |seed_info: &mut SeedInfo, interior_map: &InteriorMap| {
+        seed_info.world_graph = build_partial_graph(seed_info, interior_map, &vanilla_paths);
+        filler::assumed_reachable_locations(seed_info, &check_map, &progression_pool)
+    };
+
+    let mut interior_map = InteriorMap::default();
+    let mut free_interiors = shuffle(rng, ids.clone());
+    let mut free_doors = shuffle(rng, ids.clone());
+
+    loop {
+        let reachable = find_reachable(seed_info, &interior_map);
+        let pending = get_pending(&ids, &interior_map, &reachable, &doors, &exits);
+        if pending.is_empty() {
+            break;
+        }
+
+        let next = pending[rng.gen_range(0..pending.len())];
+        let candidates = match next {
+            Pending::Door(_) => &mut free_interiors,
+            Pending::Exit(_) => &mut free_doors,
+        };
+
+        // Interior exits lead out to doors that can't be reached yet first, so the layout keeps growing outward.
+        let mut order = (0..candidates.len()).collect::<Vec<_>>();
+        if let Pending::Exit(_) = next {
+            order.sort_by_key(|&index| reachable.contains(&doors[&candidates[index]].get_location()));
+        }
+
+        // Take the first destination that keeps something else reachable to assign, unless this is the last one. While
+        // doors remain out of reach, take whichever shrinks what's left to assign the least: linking two reachable
+        // loose ends together wastes one that could have opened up a new region.
+        let unreached = ids.iter().any(|id| !reachable.contains(&doors[id].get_location()));
+        let mut chosen = None;
+        let mut most_pending = 0;
+        for index in order {
+            let mut attempt = interior_map.clone();
+            assign(&mut attempt, next, candidates[index], coupled);
+
+            let remaining = ids.len() * 2 - attempt.doors.len() - attempt.exits.len();
+            let reachable = find_reachable(seed_info, &attempt);
+            let still_pending = get_pending(&ids, &attempt, &reachable, &doors, &exits).len();
+            if remaining == 0 || (still_pending > most_pending) {
+                chosen = Some(index);
+                most_pending = still_pending;
+                if remaining == 0 || !unreached || still_pending >= pending.len() {
+                    break;
+                }
+            }
+        }
+
+        let Some(index) = chosen else {
+            return Err(Error::game("Could not place the remaining Interiors without cutting off an entrance"));
+        };
+        let candidate = candidates.remove(index);
+        if coupled {
+            // The exit of the interior placed behind a door always leads back to that door
+            match next {
+                Pending::Door(door) => free_doors.retain(|&id| id != door),
+                Pending::Exit(interior) => free_interiors.retain(|&id| id != interior),
+            }
+        }
+        assign(&mut interior_map, next, candidate, coupled);
+    }
+
+    if interior_map.doors.len() != ids.len() || interior_map.exits.len() != ids.len() {
+        return Err(Error::game("Some Interior Shuffle doors can't be reached from the start"));
+    }
+
+    Ok(interior_map)
+}
+
+fn assign(interior_map: &mut InteriorMap, pending: Pending, destination: LoadingZoneId, coupled: bool) {
+    match pending {
+        Pending::Door(door) => {
+            interior_map.doors.insert(door, destination);
+            if coupled {
+                interior_map.exits.insert(destination, door);
+            }
+        },
+        Pending::Exit(interior) => {
+            interior_map.exits.insert(interior, destination);
+            if coupled {
+                interior_map.doors.insert(destination, interior);
+            }
+        },
+    }
+}
+
+/// Unassigned doors outside, and unassigned exits inside, a reachable location.
+fn get_pending(
+    ids: &[LoadingZoneId], interior_map: &InteriorMap, reachable: &HashSet<Location>,
+    doors: &HashMap<LoadingZoneId, LoadingZone>, exits: &HashMap<LoadingZoneId, LoadingZone>,
+) -> Vec<Pending> {
+    let mut pending = Vec::new();
+    for &id in ids {
+        if !interior_map.doors.contains_key(&id) && reachable.contains(&doors[&id].get_location()) {
+            pending.push(Pending::Door(id));
+        }
+        if !interior_map.exits.contains_key(&id) && reachable.contains(&exits[&id].get_location()) {
+            pending.push(Pending::Exit(id));
+        }
+    }
+    pending
+}
+
+/// The World Graph path taken through each door and interior exit, as its origin and index amongst that Location's
+/// paths. Redirects keep a path's index, so these also identify the paths in a shuffled World Graph.
+fn get_vanilla_paths(
+    seed_info: &SeedInfo, ids: &[LoadingZoneId], doors: &HashMap<LoadingZoneId, LoadingZone>,
+    exits: &HashMap<LoadingZoneId, LoadingZone>,
+) -> HashMap<Pending, (Location, usize)> {
+    let find_path = |from: Location, to: Location| {
+        let node =
+            seed_info.world_graph.get(&from).unwrap_or_else(|| panic!("World Graph missing Location: {:?}", from));
+        let index = node
+            .get_paths()
+            .iter()
+            .flatten()
+            .position(|path| !path.is_crack() && path.get_destination() == to)
+            .unwrap_or_else(|| panic!("World Graph missing path from {:?} to {:?}", from, to));
+        (from, index)
+    };
+
+    ids.iter()
+        .flat_map(|&id| {
+            let (door, exit) = (doors[&id].get_location(), exits[&id].get_location());
+            [(Pending::Door(id), find_path(door, exit)), (Pending::Exit(id), find_path(exit, door))]
+        })
+        .collect()
+}
+
+/// The World Graph with the doors and interior exits assigned so far, and no way through those that aren't yet.
+fn build_partial_graph(
+    seed_info: &SeedInfo, interior_map: &InteriorMap, vanilla_paths: &HashMap<Pending, (Location, usize)>,
+) -> WorldGraph {
+    let mut world_graph = world::build_world_graph(&seed_info.crack_map, &seed_info.dungeon_entrance_map, interior_map);
+
+    let mut unassigned = HashMap::<Location, HashSet<usize>>::new();
+    for (pending, &(location, index)) in vanilla_paths {
+        let assigned = match pending {
+            Pending::Door(door) => interior_map.doors.contains_key(door),
+            Pending::Exit(interior) => interior_map.exits.contains_key(interior),
+        };
+        if !assigned {
+            unassigned.entry(location).or_default().insert(index);
+        }
+    }
+
+    for (location, indices) in unassigned {
+        world_graph.get_mut(&location).unwrap().remove_paths(&indices);
+    }
+    world_graph
+}
@@ -5,9 +5,11 @@ use crate::filler::filler_item::Vane;
 use crate::filler::util::shuffle;
 use crate::SeedInfo;
 use modinfo::settings::cracks::Cracks;
+use modinfo::settings::fill_algorithm::FillAlgorithm;
 use modinfo::settings::keysy::Keysy;
 use modinfo::settings::logic::LogicMode;
 use modinfo::settings::nice_items::NiceItems;
+use modinfo::settings::shop_prices::ShopPrices;
 use modinfo::Settings;
 use rand::{rngs::StdRng, Rng};
 use std::cmp::Ordering;
@@ -23,8 +25,8 @@ pub type Pool = Vec<Item>;
  */
 pub(crate) fn get_item_pools(rng: &mut StdRng, SeedInfo { settings, .. }: &SeedInfo) -> (Pool, Pool) {
     let mut progression_items = get_base_progression_pool();
-    let minor_progression = get_minor_progression_pool();
-    let dungeon_prizes = get_dungeon_prize_pool();
+    let mut minor_progression = get_minor_progression_pool();
+    let mut dungeon_prizes = get_dungeon_prize_pool();
     let big_keys = get_big_key_pool(settings);
     let small_keys = get_small_key_pool(settings);
     let compasses = get_compass_pool();
@@ -86,6 +88,16 @@ pub(crate) fn get_item_pools(rng: &mut StdRng, SeedInfo { settings, .. }: &SeedI
         progression_items.extend_from_slice(&[Sword01, Sword02, Sword03, Sword04]);
     }
 
+    // Dungeon Prizes Anywhere: prizes join the general pool, and the prize checks take any item
+    if settings.prizes_anywhere {
+        progression_items.append(&mut dungeon_prizes);
+    }
+
+    // Heart Cap
+    let surplus_hearts = get_surplus_heart_items(settings);
+    minor_progression.retain(|item| !surplus_hearts.contains(item));
+    delta_junk_items += surplus_hearts.len() as i32;
+
     // Junk Pool. Add or remove elements from the junk pool based on chosen settings.
     let junk_pool = get_base_junk_pool(rng);
     let mut junk_pool = shuffle(rng, junk_pool);
@@ -98,13 +110,24 @@ pub(crate) fn get_item_pools(rng: &mut StdRng, SeedInfo { settings, .. }: &SeedI
         Ordering::Equal => {},
     }
 
-    (
-        shuffle_order_progression_pools(
-            rng,
-            vec![dungeon_prizes, big_keys, small_keys, compasses, progression_items, minor_progression],
-        ),
-        junk_pool,
-    )
+    // Shop Prices: shop items need Rupees found beforehand, so the Assumed Fill places them ahead of the items that
+    // lock them away. Forward Fill already places them where they can be found.
+    let progression_pools =
+        if settings.shop_prices == ShopPrices::Vanilla || settings.fill_algorithm == FillAlgorithm::ForwardFill {
+            vec![dungeon_prizes, big_keys, small_keys, compasses, progression_items, minor_progression]
+        } else {
+            let (rupees, minor_progression) = minor_progression.into_iter().partition(|item| is_rupee(*item));
+            vec![dungeon_prizes, big_keys, small_keys, compasses, rupees, progression_items, minor_progression]
+        };
+
+    (shuffle_order_progression_pools(rng, progression_pools), junk_pool)
+}
+
+/// Whether the item is one of the Rupees counted by [`Progress::has_rupees`].
+///
+/// [`Progress::has_rupees`]: crate::filler::progress::Progress::has_rupees
+fn is_rupee(item: Item) -> bool {
+    [get_gold_rupee_pool(), get_silver_rupee_pool(), get_purple_rupee_pool()].iter().any(|pool| pool.contains(&item))
 }
 
 /**
@@ -113,6 +136,7 @@ pub(crate) fn get_item_pools(rng: &mut StdRng, SeedInfo { settings, .. }: &SeedI
  * - Big Keys
  * - Small Keys
  * - Compasses
+ * - Rupees (with randomized Shop Prices and Assumed Fill only)
  * - Progression
  * - Minor progression
  */
@@ -288,6 +312,31 @@ pub fn get_heart_containers() -> Vec<Item> {
     ]
 }
 
+/// Heart Containers and Heart Pieces that would raise the player's hearts above the [`Settings::heart_cap`].
+///
+/// Heart Containers are trimmed first, then Heart Pieces in sets of four, so the cap is always reached exactly.
+pub(crate) fn get_surplus_heart_items(settings: &Settings) -> Vec<Item> {
+    let mut heart_containers = get_heart_containers();
+    let mut heart_pieces = get_heart_pieces();
+
+    let max_hearts = settings.starting_hearts as usize + heart_containers.len() + (heart_pieces.len() / 4);
+    let mut surplus = max_hearts.saturating_sub(settings.heart_cap as usize);
+
+    let mut surplus_items = Vec::new();
+    while surplus > 0 {
+        if let Some(heart_container) = heart_containers.pop() {
+            surplus_items.push(heart_container);
+        } else if heart_pieces.len() >= 4 {
+            surplus_items.extend(heart_pieces.split_off(heart_pieces.len() - 4));
+        } else {
+            break;
+        }
+        surplus -= 1;
+    }
+
+    surplus_items
+}
+
 /// Junk Pool
 fn get_base_junk_pool(rng: &mut StdRng) -> Vec<Item> {
     const BLUES: usize = 8;
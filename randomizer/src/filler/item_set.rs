@@ -0,0 +1,66 @@
+use crate::filler::cracks::Crack;
+use crate::filler::filler_item::{Goal, Item, Randomizable, Vane};
+use game::ghosts::HintGhost;
+use strum::EnumCount;
+
+const GOAL_OFFSET: usize = Item::COUNT;
+const HINT_GHOST_OFFSET: usize = GOAL_OFFSET + Goal::COUNT;
+const VANE_OFFSET: usize = HINT_GHOST_OFFSET + HintGhost::COUNT;
+const CRACK_OFFSET: usize = VANE_OFFSET + Vane::COUNT;
+const WORDS: usize = (CRACK_OFFSET + Crack::COUNT).div_ceil(u64::BITS as usize);
+
+/// A compact set of [`Randomizable`]s, storing one bit for every possible value.
+///
+/// Copying, comparing and combining sets are a handful of word operations, which keeps the logic checks performed by
+/// the filler's searches cheap.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct ItemSet([u64; WORDS]);
+
+impl ItemSet {
+    /// Adds an item to the set, returning whether it was newly added. Traps grant nothing, so are never added.
+    pub fn insert(&mut self, item: impl Into<Randomizable>) -> bool {
+        let Some((word, bit)) = Self::position(item.into()) else {
+            return false;
+        };
+        let added = self.0[word] & bit == 0;
+        self.0[word] |= bit;
+        added
+    }
+
+    pub fn contains(&self, item: impl Into<Randomizable>) -> bool {
+        Self::position(item.into()).is_some_and(|(word, bit)| self.0[word] & bit != 0)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.iter().all(|&word| word == 0)
+    }
+
+    /// Adds every item in `other` to this set.
+    pub fn extend(&mut self, other: &ItemSet) {
+        for (word, other_word) in self.0.iter_mut().zip(other.0) {
+            *word |= other_word;
+        }
+    }
+
+    /// The items in this set that are not in `other`.
+    pub fn difference(&self, other: &ItemSet) -> ItemSet {
+        let mut difference = *self;
+        for (word, other_word) in difference.0.iter_mut().zip(other.0) {
+            *word &= !other_word;
+        }
+        difference
+    }
+
+    fn position(item: Randomizable) -> Option<(usize, u64)> {
+        let index = match item {
+            Randomizable::Item(item) => item as usize,
+            Randomizable::Goal(goal) => GOAL_OFFSET + goal as usize,
+            Randomizable::HintGhost(ghost) => HINT_GHOST_OFFSET + ghost as usize,
+            Randomizable::Vane(vane) => VANE_OFFSET + vane as usize,
+            Randomizable::Crack(crack) => CRACK_OFFSET + crack as usize,
+            Randomizable::Remote(..) => unreachable!("Items for other players have no place in an ItemSet"),
+            Randomizable::Trap(_) => return None,
+        };
+        Some((index / u64::BITS as usize, 1 << (index % u64::BITS as usize)))
+    }
+}
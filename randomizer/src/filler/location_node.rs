@@ -1,6 +1,8 @@
 use crate::filler::check::Check;
+use crate::filler::location::Location;
 use crate::filler::path::Path;
 use serde::Serialize;
+use std::collections::{HashMap, HashSet};
 
 #[derive(Clone, Debug, Serialize)]
 pub struct LocationNode {
@@ -21,7 +23,28 @@ impl LocationNode {
         &self.checks
     }
 
-    pub fn get_paths(self) -> Option<Vec<Path>> {
-        self.paths
+    pub fn get_paths(&self) -> &Option<Vec<Path>> {
+        &self.paths
+    }
+
+    /// Removes the paths at the given indices
+    pub fn remove_paths(&mut self, indices: &HashSet<usize>) {
+        if let Some(paths) = &mut self.paths {
+            *paths = paths
+                .drain(..)
+                .enumerate()
+                .filter(|(index, _)| !indices.contains(index))
+                .map(|(_, path)| path)
+                .collect();
+        }
+    }
+
+    /// Points every path leading to a key of `redirects` at its value instead, keeping its logic. Cracks aren't moved.
+    pub fn redirect_paths(&mut self, redirects: &HashMap<Location, Location>) {
+        for path in self.paths.iter_mut().flatten().filter(|path| !path.is_crack()) {
+            if let Some(&to) = redirects.get(&path.get_destination()) {
+                *path = Path::new(to, path.get_logic());
+            }
+        }
     }
 }
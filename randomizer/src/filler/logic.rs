@@ -1,7 +1,7 @@
 use serde::{Serialize, Serializer};
 use std::fmt::{Debug, Formatter};
 
-use modinfo::settings::logic::LogicMode::*;
+use modinfo::settings::logic::LogicMode::{self, *};
 
 use crate::filler::progress::Progress;
 
@@ -59,24 +59,35 @@ impl Logic {
         self
     }
 
+    /// Whether this logic can be satisfied at all under the given mode, given the right items.
+    pub fn is_possible(self, logic_mode: LogicMode) -> bool {
+        match logic_mode {
+            Normal => self.normal.is_some(),
+            Hard => [self.normal, self.hard].iter().any(Option::is_some),
+            Glitched => [self.normal, self.hard, self.glitched].iter().any(Option::is_some),
+            AdvGlitched => [self.normal, self.hard, self.glitched, self.adv_glitched].iter().any(Option::is_some),
+            Hell => [self.normal, self.hard, self.glitched, self.adv_glitched, self.hell].iter().any(Option::is_some),
+            NoLogic => true,
+        }
+    }
+
     pub fn can_access(self, progress: &Progress) -> bool {
         // Progression is available if the current logic or a lower tiered logic passes
-        for logic in match progress.get_settings().logic_mode {
-            Normal => Vec::from([self.normal]),
-            Hard => Vec::from([self.normal, self.hard]),
-            Glitched => Vec::from([self.normal, self.hard, self.glitched]),
-            AdvGlitched => Vec::from([self.normal, self.hard, self.glitched, self.adv_glitched]),
-            Hell => Vec::from([self.normal, self.hard, self.glitched, self.adv_glitched, self.hell]),
+        let tiers = match progress.get_settings().logic_mode {
+            Normal => 1,
+            Hard => 2,
+            Glitched => 3,
+            AdvGlitched => 4,
+            Hell => 5,
             NoLogic => {
                 return true;
             },
-        } {
-            if logic.is_some() && (logic.unwrap())(progress) {
-                return true;
-            }
-        }
+        };
 
-        false
+        [self.normal, self.hard, self.glitched, self.adv_glitched, self.hell][..tiers]
+            .iter()
+            .flatten()
+            .any(|logic| logic(progress))
     }
 
     pub fn free() -> Self {
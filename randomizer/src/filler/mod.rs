@@ -1,32 +1,55 @@
 use crate::filler::check::Check;
+use crate::filler::diagnostics::FillFailure;
 use crate::filler::filler_item::Randomizable::{Crack, Vane};
-use crate::filler::filler_item::{Item, Randomizable};
+use crate::filler::filler_item::{Goal, Item, Randomizable};
 use crate::filler::item_pools::{get_maiamai_pool, Pool};
 use crate::filler::location::Location;
 use crate::filler::progress::Progress;
+use crate::filler::reachability::Reachability;
+use crate::filler::util::shuffle;
+use crate::regions::World;
 use crate::{world::WorldGraph, CheckMap, DashMap, SeedInfo};
 use log::{debug, error, info};
 use macros::fail;
+use modinfo::settings::accessibility::Accessibility;
+use modinfo::settings::compass_shuffle::CompassShuffle;
+use modinfo::settings::fill_algorithm::FillAlgorithm;
+use modinfo::settings::keysanity::Keysanity;
 use modinfo::settings::logic::LogicMode;
+use modinfo::settings::maiamai_rewards::MaiamaiRewards;
+use modinfo::settings::maiamai_shuffle::MaiamaiShuffle;
 use modinfo::settings::nice_items::NiceItems;
-use path::Path;
-use queue::Queue;
+use modinfo::Settings;
 use rand::{rngs::StdRng, Rng};
 use rom::Error;
 use std::collections::HashSet;
 
+pub(crate) mod boss_hunt;
+pub mod bosses;
 pub mod check;
 pub mod cracks;
+pub mod diagnostics;
+pub mod dungeon_entrances;
 pub mod filler_item;
+mod forward_fill;
+pub mod interiors;
 pub mod item_pools;
+pub mod item_set;
 mod loading_zone_pair;
 pub mod location;
 pub mod location_node;
 pub mod logic;
+pub(crate) mod multiworld;
 pub mod path;
+pub(crate) mod plando;
 pub mod progress;
+mod progression_balancing;
+pub(crate) mod reachability;
+pub(crate) mod shop_prices;
+pub mod start;
 pub(crate) mod text;
 pub mod tower_stage;
+pub(crate) mod traps;
 pub(crate) mod treacherous_tower;
 pub(crate) mod trials;
 pub mod util;
@@ -38,20 +61,41 @@ pub mod vanes;
 pub fn fill_all_locations_reachable(
     rng: &mut StdRng, seed_info: &mut SeedInfo, check_map: &mut CheckMap,
 ) -> crate::Result<()> {
+    let (mut progression_pool, mut junk_pool) = prepare_world(rng, seed_info, check_map)?;
+
+    fill_progression(rng, seed_info, check_map, &mut progression_pool, &mut junk_pool)?;
+    fill_junk(rng, seed_info, check_map, &mut junk_pool)?;
+
+    if seed_info.settings.fill_algorithm == FillAlgorithm::ProgressionBalancing {
+        progression_balancing::balance_progression(rng, seed_info, check_map);
+    }
+
+    finish_world(seed_info, check_map)
+}
+
+/// Places everything decided before the progression fill: Cracks, Weather Vanes, static and Plando items, and junk on
+/// excluded checks. Returns the progression and junk items left to place.
+fn prepare_world(rng: &mut StdRng, seed_info: &mut SeedInfo, check_map: &mut CheckMap) -> crate::Result<(Pool, Pool)> {
     let (mut progression_pool, mut junk_pool) = item_pools::get_item_pools(rng, seed_info);
 
     place_cracks(seed_info, check_map);
     place_weather_vanes(seed_info, check_map);
 
-    verify_all_locations_accessible(seed_info, check_map, &mut progression_pool)?;
+    verify_accessibility(seed_info, check_map, &mut progression_pool)?;
 
     preplace_items(rng, seed_info, check_map, &mut progression_pool)?;
+    plando::place_plando_items(seed_info, check_map, &mut progression_pool, &mut junk_pool)?;
     handle_exclusions(rng, seed_info, check_map, &mut junk_pool);
 
-    assumed_fill(rng, seed_info, check_map, &mut progression_pool, &mut junk_pool)?;
-    fill_junk(rng, check_map, &mut junk_pool)?;
+    Ok((progression_pool, junk_pool))
+}
 
+/// Records the filled `check_map` into the world's [`Layout`].
+///
+/// [`Layout`]: crate::Layout
+fn finish_world(seed_info: &mut SeedInfo, check_map: &mut CheckMap) -> crate::Result<()> {
     build_layout(seed_info, check_map)?;
+    find_unreachable_checks(seed_info, check_map);
 
     Ok(())
 }
@@ -159,10 +203,12 @@ fn place_weather_vanes(SeedInfo { vane_map, .. }: &SeedInfo, check_map: &mut Che
 
 /// Place static items ahead of the randomly filled ones
 fn preplace_items(
-    rng: &mut StdRng, SeedInfo { settings, .. }: &SeedInfo, check_map: &mut CheckMap, progression: &mut Vec<Item>,
+    rng: &mut StdRng, seed_info: &SeedInfo, check_map: &mut CheckMap, progression: &mut Vec<Item>,
 ) -> crate::Result<()> {
+    let settings = &seed_info.settings;
+
     // Vanilla Dungeon Prizes
-    if !&settings.dungeon_prize_shuffle {
+    if !&settings.dungeon_prize_shuffle && !settings.prizes_anywhere {
         place_static(check_map, progression, Item::PendantOfCourage, "[EP] Prize");
         place_static(check_map, progression, Item::PendantOfWisdom, "[HG] Prize");
         place_static(check_map, progression, Item::PendantOfPower, "[TH] Prize");
@@ -175,6 +221,21 @@ fn preplace_items(
         place_static(check_map, progression, Item::SageRosso, "[IR] Prize");
     }
 
+    // Vanilla Compasses
+    if settings.compass_shuffle == CompassShuffle::Vanilla {
+        place_static(check_map, progression, Item::EasternCompass, "[EP] (1F) Popo Room");
+        place_static(check_map, progression, Item::GalesCompass, "[HG] (1F) West Room");
+        place_static(check_map, progression, Item::HeraCompass, "[TH] (1F) Center");
+        place_static(check_map, progression, Item::DarkCompass, "[PD] (1F) Switch Puzzle");
+        place_static(check_map, progression, Item::SwampCompass, "[SP] (B1) Center");
+        place_static(check_map, progression, Item::SkullCompass, "[SW] (B1) Gibdo Room (Lower)");
+        place_static(check_map, progression, Item::ThievesCompass, "[TT] (B2) Jail Cell");
+        place_static(check_map, progression, Item::TurtleCompass, "[TR] (1F) Center");
+        place_static(check_map, progression, Item::DesertCompass, "[DP] (1F) Sand Switch Room");
+        place_static(check_map, progression, Item::IceCompass, "[IR] (B1) East Chest");
+        place_static(check_map, progression, Item::LoruleCastleCompass, "[LC] (4F) Center");
+    }
+
     // Place un-randomized items
     place_static(check_map, progression, Item::RupeeSilver41, "[TR] (1F) Under Center");
     place_static(check_map, progression, Item::RupeeGold09, "[TR] (B1) Under Center");
@@ -284,25 +345,51 @@ fn preplace_items(
 
     insert_items_into_random_locations(shop_items, shop_positions, rng, check_map, progression)?;
 
-    // For non-Maiamai Madness seeds, default them to Maiamai
+    // Maiamai that aren't being shuffled are placed in their vanilla locations
     // FIXME Inefficient to add Maiamai to progression pool, shuffle, then remove them
-    if !&settings.maiamai_madness {
-        let mut maiamai_positions: Vec<String> = Vec::new();
-        for (check_name, _) in check_map.iter() {
-            if check_name.starts_with("[Mai]") {
-                maiamai_positions.push(check_name.clone());
-            }
-        }
-
-        let mut maiamai_items = get_maiamai_pool();
-        for check_name in maiamai_positions {
-            place_static(check_map, progression, maiamai_items.remove(0), &check_name);
-        }
+    let mut maiamai_items = get_maiamai_pool();
+    for check_name in get_static_maiamai_checks(rng, seed_info) {
+        place_static(check_map, progression, maiamai_items.remove(0), check_name);
     }
 
     Ok(())
 }
 
+/// Determines which Maiamai checks keep their vanilla Maiamai, according to the Maiamai Madness settings.
+fn get_static_maiamai_checks(rng: &mut StdRng, seed_info: &SeedInfo) -> Vec<&'static str> {
+    let mut maiamai_checks = seed_info
+        .world_graph
+        .values()
+        .flat_map(|location_node| location_node.clone().get_checks().iter().flatten().copied().collect::<Vec<_>>())
+        .filter(|check| check.get_name().starts_with("[Mai]"))
+        .map(|check| (check.get_name(), check.get_location_info().map(|info| info.world())))
+        .collect::<Vec<_>>();
+    maiamai_checks.sort_by_key(|(name, _)| *name);
+    maiamai_checks.dedup_by_key(|(name, _)| *name);
+
+    if !seed_info.settings.maiamai_madness {
+        return maiamai_checks.into_iter().map(|(name, _)| name).collect();
+    }
+
+    match seed_info.settings.maiamai_shuffle {
+        MaiamaiShuffle::All => vec![],
+        MaiamaiShuffle::Hyrule => maiamai_checks
+            .into_iter()
+            .filter(|(_, world)| *world != Some(World::Hyrule))
+            .map(|(name, _)| name)
+            .collect(),
+        MaiamaiShuffle::Lorule => maiamai_checks
+            .into_iter()
+            .filter(|(_, world)| *world != Some(World::Lorule))
+            .map(|(name, _)| name)
+            .collect(),
+        MaiamaiShuffle::Random(amount) => {
+            let mut maiamai_checks = shuffle(rng, maiamai_checks.into_iter().map(|(name, _)| name).collect());
+            maiamai_checks.split_off((amount as usize).min(maiamai_checks.len()))
+        },
+    }
+}
+
 /// Inserts the given `items` randomly into the list of `locations`, and updates the `check_map` and `progression` pool
 /// accordingly. Both lists are consumed.
 fn insert_items_into_random_locations(
@@ -325,27 +412,22 @@ fn handle_exclusions(rng: &mut StdRng, seed_info: &mut SeedInfo, check_map: &mut
         seed_info.full_exclusions.insert("100 Maiamai".to_string());
     }
 
-    // Exclude all Maiamai checks when the Maiamai Limit is (effectively) zero.
-    if seed_info.settings.maiamai_limit / 10 == 0 {
-        seed_info.full_exclusions.insert("Maiamai Bow Upgrade".to_string());
-        seed_info.full_exclusions.insert("Maiamai Boomerang Upgrade".to_string());
-        seed_info.full_exclusions.insert("Maiamai Hookshot Upgrade".to_string());
-        seed_info.full_exclusions.insert("Maiamai Hammer Upgrade".to_string());
-        seed_info.full_exclusions.insert("Maiamai Bombs Upgrade".to_string());
-        seed_info.full_exclusions.insert("Maiamai Fire Rod Upgrade".to_string());
-        seed_info.full_exclusions.insert("Maiamai Ice Rod Upgrade".to_string());
-        seed_info.full_exclusions.insert("Maiamai Tornado Rod Upgrade".to_string());
-        seed_info.full_exclusions.insert("Maiamai Sand Rod Upgrade".to_string());
+    // Exclude all Maiamai checks when the Maiamai Limit is (effectively) zero, or only those past the limit when
+    // rewards are handed out at milestones.
+    let maiamai_rewards_allowed = match seed_info.settings.maiamai_rewards {
+        MaiamaiRewards::Upgrades if seed_info.settings.maiamai_limit / 10 == 0 => 0,
+        MaiamaiRewards::Upgrades => MAIAMAI_UPGRADE_CHECKS.len(),
+        MaiamaiRewards::Milestones => seed_info.settings.maiamai_limit / 10,
+    };
+    for check_name in MAIAMAI_UPGRADE_CHECKS.iter().skip(maiamai_rewards_allowed) {
+        seed_info.full_exclusions.insert(check_name.to_string());
     }
 
     // Exclude Minigames
     if seed_info.settings.minigames_excluded {
-        seed_info.full_exclusions.insert("Dodge the Cuccos".to_string());
-        seed_info.full_exclusions.insert("Hyrule Hotfoot 75s".to_string());
-        seed_info.full_exclusions.insert("Hyrule Hotfoot 65s".to_string());
-        seed_info.full_exclusions.insert("Rupee Rush (Hyrule)".to_string());
-        seed_info.full_exclusions.insert("Rupee Rush (Lorule)".to_string());
-        seed_info.full_exclusions.insert("Octoball Derby".to_string());
+        for check_name in MINIGAME_CHECKS {
+            seed_info.full_exclusions.insert(check_name.to_string());
+        }
 
         // For Maiamai Madness, also turn the rupee rush maiamai into random junk
         if seed_info.settings.maiamai_madness {
@@ -474,7 +556,9 @@ fn is_dungeon_item(item: Item) -> bool {
     )
 }
 
-fn fill_junk(rng: &mut StdRng, check_map: &mut CheckMap, junk_items: &mut Pool) -> Result<(), Error> {
+fn fill_junk(
+    rng: &mut StdRng, seed_info: &SeedInfo, check_map: &mut CheckMap, junk_items: &mut Pool,
+) -> Result<(), Error> {
     info!("Placing Junk Items...");
 
     let mut empty_check_keys = Vec::new();
@@ -484,7 +568,12 @@ fn fill_junk(rng: &mut StdRng, check_map: &mut CheckMap, junk_items: &mut Pool)
         }
     }
 
-    if empty_check_keys.len() != junk_items.len() {
+    if empty_check_keys.len() < junk_items.len() {
+        return Err(Error::new(FillFailure::out_of_checks(
+            format!("{:?}", junk_items[empty_check_keys.len()]),
+            check_map.len(),
+        )));
+    } else if empty_check_keys.len() > junk_items.len() {
         return Err(Error::new(format!(
             "Number of empty checks: {} does not match available junk items: {}",
             empty_check_keys.len(),
@@ -492,40 +581,103 @@ fn fill_junk(rng: &mut StdRng, check_map: &mut CheckMap, junk_items: &mut Pool)
         )));
     }
 
-    for junk in junk_items {
-        check_map.insert(empty_check_keys.remove(rng.gen_range(0..empty_check_keys.len())), Some((*junk).into()));
+    for junk in traps::add_traps(rng, &seed_info.settings, junk_items) {
+        check_map.insert(empty_check_keys.remove(rng.gen_range(0..empty_check_keys.len())), Some(junk));
     }
 
     Ok(())
 }
 
-fn filter_checks(item: Item, checks: &[Check], check_map: &mut CheckMap) -> Vec<Check> {
+fn filter_checks(item: Item, checks: &[Check], seed_info: &SeedInfo, check_map: &mut CheckMap) -> Vec<Check> {
     // Filter out non-empty checks
-    let mut filtered_checks =
+    let filtered_checks =
         checks.iter().filter(|&x| check_map.get(x.get_name()).unwrap().is_none()).cloned().collect::<Vec<_>>();
 
-    // Filter checks by item type
-    if is_dungeon_prize(item) {
-        filtered_checks = filter_dungeon_prize_checks(&filtered_checks);
+    filter_checks_by_item(item, filtered_checks, &seed_info.settings)
+}
+
+/// Filters out the checks the given `item` isn't allowed on, whether or not they're empty.
+fn filter_checks_by_item(item: Item, checks: Vec<Check>, settings: &Settings) -> Vec<Check> {
+    if is_dungeon_prize(item) && !settings.prizes_anywhere {
+        filter_dungeon_prize_checks(&checks)
     } else if is_dungeon_item(item) {
-        let is_keysanity = false; // No keysanity yet, hardcode to false
-        if !is_keysanity {
-            filtered_checks = filter_dungeon_checks(item, filtered_checks);
+        match get_keysanity(item, settings) {
+            Keysanity::OwnDungeon => filter_dungeon_checks(item, checks),
+            Keysanity::OwnWorld => filter_world_checks(item, checks),
+            Keysanity::Anywhere => checks,
         }
+    } else {
+        checks
     }
+}
 
-    filtered_checks
+/// Gets the Keysanity setting that applies to the given dungeon item.
+fn get_keysanity(item: Item, settings: &Settings) -> Keysanity {
+    match item.to_game_item() {
+        game::Item::KeySmall => settings.small_keysanity,
+        game::Item::KeyBoss => settings.big_keysanity,
+        game::Item::Compass if settings.compass_shuffle == CompassShuffle::Anywhere => Keysanity::Anywhere,
+        _ => Keysanity::OwnDungeon,
+    }
 }
 
-fn filter_dungeon_prize_checks(eligible_checks: &[Check]) -> Vec<Check> {
-    let dungeon_prize_checks = [
-        "[EP] Prize", "[HG] Prize", "[TH] Prize", "[PD] Prize", "[SP] Prize", "[SW] Prize", "[TT] Prize", "[TR] Prize",
-        "[DP] Prize", "[IR] Prize",
-    ];
+/// Determines whether a check is in Hyrule or Lorule, counting dungeon checks as part of the world they're in.
+fn get_check_world(check: &Check) -> Option<World> {
+    Some(check.get_location_info()?.home_world())
+}
+
+/// Gets the world the dungeon the given `item` belongs to is found in.
+fn get_dungeon_world(item: Item) -> World {
+    use crate::regions::{self, dungeons::*};
+    use Item::*;
 
+    match item {
+        HyruleSanctuaryKey => regions::hyrule::WORLD,
+        LoruleSanctuaryKey => regions::lorule::WORLD,
+        EasternCompass | EasternKeyBig | EasternKeySmall01 | EasternKeySmall02 => eastern::HOME_WORLD,
+        GalesCompass | GalesKeyBig | GalesKeySmall01 | GalesKeySmall02 | GalesKeySmall03 | GalesKeySmall04 => {
+            house::HOME_WORLD
+        },
+        HeraCompass | HeraKeyBig | HeraKeySmall01 | HeraKeySmall02 => tower::HOME_WORLD,
+        DarkCompass | DarkKeyBig | DarkKeySmall01 | DarkKeySmall02 | DarkKeySmall03 | DarkKeySmall04 => {
+            dark::HOME_WORLD
+        },
+        SwampCompass | SwampKeyBig | SwampKeySmall01 | SwampKeySmall02 | SwampKeySmall03 | SwampKeySmall04 => {
+            swamp::HOME_WORLD
+        },
+        SkullCompass | SkullKeyBig | SkullKeySmall01 | SkullKeySmall02 | SkullKeySmall03 => skull::HOME_WORLD,
+        ThievesCompass | ThievesKeyBig | ThievesKeySmall => thieves::HOME_WORLD,
+        IceCompass | IceKeyBig | IceKeySmall01 | IceKeySmall02 | IceKeySmall03 => ice::HOME_WORLD,
+        DesertCompass | DesertKeyBig | DesertKeySmall01 | DesertKeySmall02 | DesertKeySmall03 | DesertKeySmall04
+        | DesertKeySmall05 => desert::HOME_WORLD,
+        TurtleCompass | TurtleKeyBig | TurtleKeySmall01 | TurtleKeySmall02 | TurtleKeySmall03 => turtle::HOME_WORLD,
+        LoruleCastleCompass
+        | LoruleCastleKeySmall01
+        | LoruleCastleKeySmall02
+        | LoruleCastleKeySmall03
+        | LoruleCastleKeySmall04
+        | LoruleCastleKeySmall05 => lorule::HOME_WORLD,
+        _ => {
+            fail!("Item {:?} is not a dungeon item", item);
+        },
+    }
+}
+
+/// Filters the eligible checks to those in the same world as the dungeon the given `item` belongs to.
+fn filter_world_checks(item: Item, eligible_checks: Vec<Check>) -> Vec<Check> {
+    let world = get_dungeon_world(item);
+    eligible_checks.into_iter().filter(|check| get_check_world(check) == Some(world)).collect()
+}
+
+const DUNGEON_PRIZE_CHECKS: [&str; 10] = [
+    "[EP] Prize", "[HG] Prize", "[TH] Prize", "[PD] Prize", "[SP] Prize", "[SW] Prize", "[TT] Prize", "[TR] Prize",
+    "[DP] Prize", "[IR] Prize",
+];
+
+fn filter_dungeon_prize_checks(eligible_checks: &[Check]) -> Vec<Check> {
     eligible_checks
         .iter()
-        .filter_map(|check| if dungeon_prize_checks.contains(&check.get_name()) { Some(*check) } else { None })
+        .filter_map(|check| if DUNGEON_PRIZE_CHECKS.contains(&check.get_name()) { Some(*check) } else { None })
         .collect()
 }
 
@@ -625,19 +777,6 @@ fn filter_dungeon_checks(item: Item, eligible_checks: Vec<Check>) -> Vec<Check>
         .collect()
 }
 
-fn exist_empty_reachable_check(checks: &Vec<Check>, check_map: &mut CheckMap) -> bool {
-    for check in checks {
-        match check_map.get(check.get_name()).unwrap() {
-            None => {
-                return true;
-            },
-            Some(_) => {},
-        }
-    }
-
-    false
-}
-
 /// Prefills a map with all checks as defined by the world graph with no values yet assigned
 pub fn prefill_check_map(world_graph: &mut WorldGraph) -> CheckMap {
     let mut check_map: DashMap<_, _> = Default::default();
@@ -663,6 +802,59 @@ fn build_progress_from_items<'s>(items: &Pool, seed_info: &'s SeedInfo) -> Progr
     progress
 }
 
+/// Whether, assuming we have all possible pieces of player progression, all locations in the world graph can be reached.
+/// Used to reject randomized layouts before filling begins.
+pub(crate) fn all_locations_accessible(rng: &StdRng, seed_info: &mut SeedInfo) -> bool {
+    let (mut check_map, mut progression_pool) = assumed_progression(rng, seed_info);
+    verify_accessibility(seed_info, &mut check_map, &mut progression_pool).is_ok()
+}
+
+/// All possible pieces of player progression, along with the cracks and weather vanes placed as they will be for the
+/// fill. Used to search the world graph with [`assumed_reachable_locations`] before filling begins.
+pub(crate) fn assumed_progression(rng: &StdRng, seed_info: &mut SeedInfo) -> (CheckMap, Pool) {
+    let mut check_map = prefill_check_map(&mut seed_info.world_graph);
+    let (progression_pool, _) = item_pools::get_item_pools(&mut rng.clone(), seed_info);
+
+    place_cracks(seed_info, &mut check_map);
+    place_weather_vanes(seed_info, &mut check_map);
+
+    (check_map, progression_pool)
+}
+
+/// Every location in the world graph that can be reached with the [`assumed_progression`].
+pub(crate) fn assumed_reachable_locations(
+    seed_info: &SeedInfo, check_map: &CheckMap, progression_pool: &Pool,
+) -> HashSet<Location> {
+    assumed_search(seed_info, progression_pool, check_map).get_visited().clone()
+}
+
+/// Verifies the world graph meets the Accessibility setting, assuming we have all possible pieces of player progression.
+fn verify_accessibility(
+    seed_info: &SeedInfo, check_map: &mut CheckMap, progression_pool: &mut Pool,
+) -> Result<(), Error> {
+    match seed_info.settings.accessibility {
+        Accessibility::AllLocations => verify_all_locations_accessible(seed_info, check_map, progression_pool),
+        Accessibility::BeatableOnly => verify_beatable(seed_info, check_map, progression_pool),
+    }
+}
+
+/// Verifies that, assuming we have all possible pieces of player progression, the game can be beaten.
+fn verify_beatable(seed_info: &SeedInfo, check_map: &mut CheckMap, progression_pool: &mut Pool) -> Result<(), Error> {
+    if LogicMode::NoLogic.eq(&seed_info.settings.logic_mode) {
+        return Ok(()); // Skip this check on No Logic
+    }
+
+    info!("Verifying game is beatable...");
+    let mut reachability = Reachability::new(build_progress_from_items(progression_pool, seed_info));
+    reachability.expand(seed_info, check_map, |_| false);
+
+    if reachability.get_progress().has(Goal::Triforce) {
+        Ok(())
+    } else {
+        Err(Error::new("The Triforce could not be reached in the world graph"))
+    }
+}
+
 /// Verifies that, assuming we have all possible pieces of player progression, all locations in the world graph can be
 /// reached. This is the baseline check for an uncompletable seed, usually because of something like Entrance or Crack
 /// randomization resulting in a layout that renders certain locations inaccessible.
@@ -674,82 +866,59 @@ fn verify_all_locations_accessible(
     }
 
     info!("Verifying all locations accessible...");
-    let reachable_checks = assumed_search(seed_info, progression_pool, check_map); //find_reachable_checks(loc_map, &everything, &mut check_map); //
-
-    const STANDARD_CHECKS: usize = 264;
-    const MAIAMAI: usize = 100;
-    const DUNGEON_PRIZES: usize = 10;
-    const STATIC_ITEMS: usize = 20;
-    const PROGRESSION_EVENTS: usize = 36; // "Progression Events" (non-item checks that are still progression)
-    const WEATHER_VANES: usize = 22;
-    const HINT_GHOSTS_OW: usize = 58; // Hint Ghosts (Overworld)
-
-    // (2 Golden Bees for 9,999 Rupees NOT included)
-    const IN_LOGIC_CHECKS: usize = STANDARD_CHECKS + MAIAMAI + DUNGEON_PRIZES + STATIC_ITEMS;
-
-    /// Total count of expected, reachable checks
-    const EXPECTED_REACHABLE: usize = IN_LOGIC_CHECKS + PROGRESSION_EVENTS + WEATHER_VANES + HINT_GHOSTS_OW;
-
-    if reachable_checks.len() != EXPECTED_REACHABLE {
-        // let reachable_check_names: Vec<&str> = reachable_checks.iter().map(|c| c.get_name()).collect();
-        // for check in check_map.keys() {
-        //     if reachable_check_names.contains(&check.as_str()) {
-        //         // info!("Reachable Check: {}", check);
-        //     } else {
-        //         if !check.contains("Crack") {
-        //             info!("Unreachable Check: {}", check);
-        //         }
-        //     }
-        // }
-
-        Err(Error::new(format!(
-            "Only {}/{} checks were reachable in the world graph",
-            reachable_checks.len(),
-            EXPECTED_REACHABLE
-        )))
-    } else {
+    let reachability = assumed_search(seed_info, progression_pool, check_map);
+
+    let expected_checks = expected_reachable_checks(seed_info);
+    let mut unreachable_checks =
+        expected_checks.iter().filter(|(_, check)| !reachability.is_accessible(check)).collect::<Vec<_>>();
+    unreachable_checks.sort_by_key(|(_, check)| check.get_name());
+
+    if unreachable_checks.is_empty() {
         Ok(())
+    } else {
+        let mut message = format!(
+            "Only {}/{} checks were reachable in the world graph. Unreachable checks:",
+            expected_checks.len() - unreachable_checks.len(),
+            expected_checks.len()
+        );
+        for (location, check) in unreachable_checks {
+            message += &format!("\n  {}: {}", check.get_name(), reachability.describe_blocked(*location, check));
+        }
+        Err(Error::new(message))
     }
 }
 
-/// Find all checks reachable with the given Progress
-pub(crate) fn find_reachable_checks(SeedInfo { world_graph, .. }: &SeedInfo, progress: &Progress) -> Vec<Check> {
-    let start_node = Location::RavioShop;
-    let mut loc_queue: Queue<Location> = Queue::from(vec![start_node]);
-    let mut visited: HashSet<Location> = HashSet::new();
-    let mut reachable_checks: Vec<Check> = Vec::new(); // possibly switch to HashSet to avoid dupes
-
-    visited.insert(start_node);
-
-    while !loc_queue.is_empty() {
-        let location = loc_queue.dequeue().unwrap();
-
-        // Grab the location from the map, verify it is defined
-        let location_node = match world_graph.get(&location) {
-            Some(loc) => loc,
-            None => {
-                fail!("Location Undefined: {:?}", location);
-            },
-        };
-
-        // Iterate over the location's checks
-        for check in location_node.clone().get_checks().iter().flatten().collect::<Vec<&Check>>() {
-            if check.can_access(progress) {
-                reachable_checks.push(*check);
-            }
-        }
+/// Every check in the World Graph the current Logic Mode allows for, with the Location it lies in.
+fn expected_reachable_checks(seed_info: &SeedInfo) -> Vec<(Location, Check)> {
+    let logic_mode = seed_info.settings.logic_mode;
+    seed_info
+        .world_graph
+        .iter()
+        .flat_map(|(&location, node)| node.get_checks().iter().flatten().map(move |&check| (location, check)))
+        .filter(|(_, check)| check.get_logic().is_possible(logic_mode))
+        .collect()
+}
 
-        // Queue new paths reachable from this location
-        for path in location_node.clone().get_paths().iter().flatten().collect::<Vec<&Path>>() {
-            let destination = path.get_destination();
-            if !visited.contains(&destination) && path.can_access(progress) {
-                loc_queue.queue(destination).expect("TODO: panic message");
-                visited.insert(destination);
-            }
-        }
-    }
+/// Find all checks reachable with the given Progress
+pub(crate) fn find_reachable_checks(seed_info: &SeedInfo, progress: &Progress) -> Vec<Check> {
+    reachability::traverse_world_graph(
+        seed_info,
+        |check| check.can_access(progress),
+        |_, _, path| path.can_access(progress),
+    )
+}
 
-    reachable_checks
+/// Items that will be picked up from the given `check`: whatever has been placed there, plus any quest item that is
+/// always found there.
+pub(crate) fn get_check_items<'a>(
+    seed_info: &'a SeedInfo, check: &Check, check_map: &CheckMap,
+) -> impl Iterator<Item = Randomizable> + 'a {
+    let placed_item = *check_map.get(check.get_name()).unwrap();
+
+    [placed_item, check.get_quest()].into_iter().flatten().map(|item| match item {
+        Vane(vane) => (*seed_info.vane_map.get(&vane).unwrap()).into(),
+        _ => item,
+    })
 }
 
 pub(crate) fn get_items_from_reachable_checks<'s>(
@@ -758,28 +927,26 @@ pub(crate) fn get_items_from_reachable_checks<'s>(
     let mut progress = Progress::new(seed_info);
 
     for check in reachable_checks {
-        // Items already placed in the world that can be picked up
-        let placed_item = check_map.get(check.get_name()).unwrap();
-        match placed_item {
-            None => {},
-            Some(item) => match item {
-                Vane(vane) => progress.add_item(*seed_info.vane_map.get(vane).unwrap()),
-                _ => progress.add_item(*item),
-            },
-        }
-
-        // Quest items that will always be at a given check
-        if let Some(quest) = check.get_quest() {
-            match quest {
-                Vane(vane) => progress.add_item(*seed_info.vane_map.get(&vane).unwrap()),
-                _ => progress.add_item(quest),
-            }
+        for item in get_check_items(seed_info, check, check_map) {
+            progress.add_item(item);
         }
     }
 
     progress
 }
 
+/// Places the progression items with the chosen [`FillAlgorithm`].
+fn fill_progression(
+    rng: &mut StdRng, seed_info: &SeedInfo, check_map: &mut CheckMap, items_owned: &mut Pool, junk: &mut Pool,
+) -> crate::Result<()> {
+    match seed_info.settings.fill_algorithm {
+        FillAlgorithm::AssumedFill | FillAlgorithm::ProgressionBalancing => {
+            assumed_fill(rng, seed_info, check_map, items_owned, junk)
+        },
+        FillAlgorithm::ForwardFill => forward_fill::forward_fill(rng, seed_info, check_map, items_owned, junk),
+    }
+}
+
 /// The Assumed Fill algorithm
 ///
 /// Randomly places `items_owned` into the `check_map` in a completable manner as informed by the
@@ -801,22 +968,36 @@ fn assumed_fill(
 ) -> crate::Result<()> {
     info!("Placing Progression Items...");
 
-    let mut reachable_checks = assumed_search(seed_info, items_owned, check_map);
+    let beatable_only = seed_info.settings.accessibility == Accessibility::BeatableOnly;
+    let all_checks = get_all_checks(seed_info);
+
+    let mut placed_reachability = Reachability::new(Progress::new(seed_info));
     let mut major_maiamai_items = 0;
 
-    while exist_empty_reachable_check(&reachable_checks, check_map) && !items_owned.is_empty() {
+    // Keep going while items remain: the last item placed may have filled the only check reachable without it, yet
+    // still open up more of the world (e.g. Rupees needed for expensive shops).
+    while !items_owned.is_empty() {
         let item = items_owned.remove(0);
 
-        reachable_checks = assumed_search(seed_info, items_owned, check_map);
+        let reachability = resume_assumed_search(seed_info, items_owned, check_map, &mut placed_reachability);
+
+        // Items not needed to beat the game may be placed anywhere, even where they can never be reached
+        let reachable_checks = if beatable_only && reachability.get_progress().has(Goal::Triforce) {
+            all_checks.clone()
+        } else {
+            reachability.reachable_checks(seed_info)
+        };
 
-        let filtered_checks = filter_checks(item, &reachable_checks, check_map);
+        let filtered_checks = filter_checks(item, &reachable_checks, seed_info, check_map);
 
         if filtered_checks.is_empty() {
             // info!("item:            {:?}", item);
             // info!("filtered_checks: {:?}", filtered_checks);
             // info!("check_map:       {:?}", check_map);
 
-            return Err(crate::Error::game(format!("No reachable checks found to place: {:?}", item)));
+            return Err(crate::Error::game(FillFailure::new(
+                item, &reachable_checks, &all_checks, seed_info, check_map,
+            )));
         }
 
         let chosen_check_name = place_item_randomly(item, &filtered_checks, check_map, rng);
@@ -826,6 +1007,38 @@ fn assumed_fill(
     Ok(())
 }
 
+/// Every check in the world graph, sorted by name.
+fn get_all_checks(seed_info: &SeedInfo) -> Vec<Check> {
+    let mut checks = seed_info
+        .world_graph
+        .values()
+        .flat_map(|location_node| location_node.get_checks().iter().flatten().copied())
+        .collect::<Vec<_>>();
+    checks.sort_by_key(|check| check.get_name());
+    checks
+}
+
+/// Records every in-logic item check that cannot be reached in the finished layout. Only Beatable Only seeds can have
+/// these.
+fn find_unreachable_checks(seed_info: &mut SeedInfo, check_map: &CheckMap) {
+    if seed_info.settings.accessibility != Accessibility::BeatableOnly {
+        return;
+    }
+
+    let mut reachability = Reachability::new(Progress::new(seed_info));
+    reachability.expand(seed_info, check_map, |_| false);
+    let reachable = reachability.reachable_checks(seed_info).iter().map(Check::get_name).collect::<HashSet<_>>();
+
+    let logic_mode = seed_info.settings.logic_mode;
+    let unreachable_checks = get_all_checks(seed_info)
+        .into_iter()
+        .filter(|check| check.get_location_info().is_some() && check.get_logic().is_possible(logic_mode))
+        .filter(|check| !reachable.contains(check.get_name()))
+        .map(|check| check.get_name().to_owned())
+        .collect();
+    seed_info.unreachable_checks = unreachable_checks;
+}
+
 /// Places the given `item` on a random check in the `check_map`, then returns that check's name.
 fn place_item_randomly(item: Item, checks: &[Check], check_map: &mut CheckMap, rng: &mut StdRng) -> &'static str {
     let check_name = checks.get(rng.gen_range(0..checks.len())).unwrap().get_name();
@@ -833,28 +1046,37 @@ fn place_item_randomly(item: Item, checks: &[Check], check_map: &mut CheckMap, r
     check_name
 }
 
+/// Minigames excluded by the `minigames_excluded` setting.
+const MINIGAME_CHECKS: [&str; 6] = [
+    "Dodge the Cuccos", "Hyrule Hotfoot 75s", "Hyrule Hotfoot 65s", "Rupee Rush (Hyrule)", "Rupee Rush (Lorule)",
+    "Octoball Derby",
+];
+
+/// Mother Maiamai's Upgrade checks, in the order their rewards are handed out as Milestones.
+const MAIAMAI_UPGRADE_CHECKS: [&str; 9] = [
+    "Maiamai Bow Upgrade", "Maiamai Boomerang Upgrade", "Maiamai Hookshot Upgrade", "Maiamai Hammer Upgrade",
+    "Maiamai Bombs Upgrade", "Maiamai Fire Rod Upgrade", "Maiamai Ice Rod Upgrade", "Maiamai Tornado Rod Upgrade",
+    "Maiamai Sand Rod Upgrade",
+];
+
 /// Handles the Maiamai Limit. Keeps track of the number of major items placed behind Maiamai Upgrades, and if it
 /// exceeds the `maiamai_limit` setting it will junk all the remaining Maiamai Upgrade checks.
 fn handle_maiamai_limit(
     item: Randomizable, chosen_check_name: &str, major_maiamai_items: &mut usize, seed_info: &SeedInfo,
     check_map: &mut CheckMap, junk: &mut Pool,
 ) {
-    // Early exit if we've already dealt with the limit
-    if *major_maiamai_items >= seed_info.settings.maiamai_limit / 10 {
+    // Early exit if we've already dealt with the limit, or if Milestone exclusions already enforce it
+    if *major_maiamai_items >= seed_info.settings.maiamai_limit / 10
+        || seed_info.settings.maiamai_rewards == MaiamaiRewards::Milestones
+    {
         return;
     }
 
-    const MAIAMAI_CHECK_NAMES: [&str; 9] = [
-        "Maiamai Bow Upgrade", "Maiamai Boomerang Upgrade", "Maiamai Hookshot Upgrade", "Maiamai Hammer Upgrade",
-        "Maiamai Bombs Upgrade", "Maiamai Fire Rod Upgrade", "Maiamai Ice Rod Upgrade", "Maiamai Tornado Rod Upgrade",
-        "Maiamai Sand Rod Upgrade",
-    ];
-
-    if MAIAMAI_CHECK_NAMES.contains(&chosen_check_name) && item.is_major_item() {
+    if MAIAMAI_UPGRADE_CHECKS.contains(&chosen_check_name) && item.is_major_item() {
         *major_maiamai_items += 1;
         if *major_maiamai_items >= seed_info.settings.maiamai_limit / 10 {
             // info!("HANDLE MAIAMAI LIMIT: {}, MAJOR ITEMS: {}", seed_info.settings.maiamai_limit / 10, major_maiamai_items);
-            for check_name in MAIAMAI_CHECK_NAMES {
+            for check_name in MAIAMAI_UPGRADE_CHECKS {
                 if Some(&None) == check_map.get(check_name) {
                     check_map.insert(String::from(check_name), Some(junk.pop().expect("valid junk item").into()));
                 }
@@ -868,28 +1090,104 @@ fn handle_maiamai_limit(
 /// Gets all reachable checks available with the `items_owned`, assuming all items yet to be
 /// placed will be available.
 ///
-/// A loop is performed to expand the considered items to include not just the `items_owned` but
-/// also all items already placed that are reachable with the currently considered items, until
-/// all such items have been exhausted.
-///
-fn assumed_search(seed_info: &SeedInfo, items_owned: &Pool, check_map: &mut CheckMap) -> Vec<Check> {
-    let mut considered_items = build_progress_from_items(items_owned, seed_info);
-    let mut reachable_checks: Vec<Check>;
+/// The considered items are expanded to include not just the `items_owned` but also all items
+/// already placed that are reachable with the currently considered items, until all such items
+/// have been exhausted.
+fn assumed_search<'s>(seed_info: &'s SeedInfo, items_owned: &Pool, check_map: &CheckMap) -> Reachability<'s> {
+    let mut reachability = Reachability::new(build_progress_from_items(items_owned, seed_info));
+    reachability.expand(seed_info, check_map, |_| false);
+    reachability
+}
 
-    loop {
-        reachable_checks = find_reachable_checks(seed_info, &considered_items);
-        let reachable_items = get_items_from_reachable_checks(seed_info, &reachable_checks, check_map);
+/// The Assumed Search algorithm, resumed from `placed_reachability`: what is reachable with only the items already
+/// placed. That only grows as more items are placed, so it is kept up to date between placements rather than searched
+/// again from scratch.
+fn resume_assumed_search<'s>(
+    seed_info: &'s SeedInfo, items_owned: &Pool, check_map: &CheckMap, placed_reachability: &mut Reachability<'s>,
+) -> Reachability<'s> {
+    placed_reachability.collect_placed_items(seed_info, check_map);
+    placed_reachability.expand(seed_info, check_map, |_| false);
+
+    let mut reachability = placed_reachability.clone();
+    for item in items_owned {
+        reachability.add_item(*item);
+    }
+    reachability.expand(seed_info, check_map, |_| false);
+    reachability
+}
 
-        let new_items = reachable_items.difference(&considered_items);
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{build_world, PlayerId, SeedHash};
+    use modinfo::Settings;
+    use rand::SeedableRng;
+
+    fn seed_info(settings: Settings) -> SeedInfo {
+        let rng = &mut StdRng::seed_from_u64(1);
+        let hash = SeedHash::new(1, &settings);
+        build_world(1, settings, hash, PlayerId::default(), rng).unwrap()
+    }
 
-        if new_items.is_empty() {
-            break;
-        }
+    /// Maiamai that aren't chosen by the Maiamai Shuffle keep their vanilla Maiamai.
+    #[test]
+    fn keeps_unshuffled_maiamai_static() {
+        let defaults: Settings = serde_json::from_str("{}").unwrap();
+        let static_checks = |maiamai_madness, maiamai_shuffle| {
+            let seed_info = seed_info(Settings { maiamai_madness, maiamai_shuffle, ..defaults.clone() });
+            let checks = get_static_maiamai_checks(&mut StdRng::seed_from_u64(1), &seed_info);
+            let worlds = checks
+                .iter()
+                .map(|&name| {
+                    let check = seed_info
+                        .world_graph
+                        .values()
+                        .flat_map(|node| node.get_checks().iter().flatten())
+                        .find(|check| check.get_name() == name)
+                        .unwrap();
+                    check.get_location_info().map(|info| info.world())
+                })
+                .collect::<Vec<_>>();
+            (checks, worlds)
+        };
 
-        for new_item in new_items {
-            considered_items.add_item(new_item);
-        }
+        let (all, _) = static_checks(false, MaiamaiShuffle::All);
+        assert_eq!(all.len(), 100);
+        assert!(static_checks(true, MaiamaiShuffle::All).0.is_empty());
+
+        let (hyrule, worlds) = static_checks(true, MaiamaiShuffle::Hyrule);
+        assert!(!hyrule.is_empty() && hyrule.len() < 100);
+        assert!(worlds.iter().all(|&world| world != Some(World::Hyrule)));
+
+        let (lorule, worlds) = static_checks(true, MaiamaiShuffle::Lorule);
+        assert_eq!(hyrule.len() + lorule.len(), 100);
+        assert!(worlds.iter().all(|&world| world != Some(World::Lorule)));
+
+        assert_eq!(static_checks(true, MaiamaiShuffle::Random(30)).0.len(), 70);
     }
 
-    reachable_checks
+    /// Milestone rewards are handed out in order, one for every 10 Maiamai, while Upgrades need 90 Maiamai and the
+    /// matching item.
+    #[test]
+    fn gates_maiamai_rewards() {
+        let defaults: Settings = serde_json::from_str("{}").unwrap();
+        let milestones = seed_info(Settings { maiamai_rewards: MaiamaiRewards::Milestones, ..defaults.clone() });
+        let upgrades = seed_info(defaults);
+
+        for seed_info in [&milestones, &upgrades] {
+            let mut progress = Progress::new(seed_info);
+            for maiamai in get_maiamai_pool().into_iter().take(20) {
+                progress.add_item(maiamai);
+            }
+            progress.add_item(Item::Boomerang01);
+            assert!(!progress.can_get_maiamai_reward(1));
+            assert!(!progress.can_get_maiamai_reward(2));
+
+            progress.add_item(Item::Bow01);
+            let is_milestones = seed_info.settings.maiamai_rewards == MaiamaiRewards::Milestones;
+            assert_eq!(progress.can_get_maiamai_reward(1), is_milestones);
+            assert_eq!(progress.can_get_maiamai_reward(2), is_milestones);
+            assert!(!progress.can_get_maiamai_reward(3));
+        }
+    }
 }
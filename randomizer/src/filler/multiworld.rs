@@ -0,0 +1,217 @@
+use crate::filler::diagnostics::FillFailure;
+use crate::filler::filler_item::Randomizable;
+use crate::filler::item_pools::Pool;
+use crate::filler::progress::Progress;
+use crate::filler::reachability::Reachability;
+use crate::filler::traps;
+use crate::filler::{
+    filter_checks, finish_world, get_all_checks, handle_maiamai_limit, is_dungeon_item, is_dungeon_prize, prepare_world,
+};
+use crate::{CheckMap, Error, Result, SeedInfo};
+use log::info;
+use rand::{rngs::StdRng, Rng};
+use std::collections::HashSet;
+
+/// Fills every world of a Multiworld seed together, one world per player.
+///
+/// Each world is prepared just as a single player seed would be. The progression items of all players are then placed
+/// with a shared Assumed Fill, taking turns between the players: an item may go in any world, so long as its owner can
+/// still get it. Dungeon items and Dungeon Prizes always stay in their owner's world.
+pub(crate) fn fill_multiworld(rng: &mut StdRng, worlds: &mut [SeedInfo], check_maps: &mut [CheckMap]) -> Result<()> {
+    let mut progression_pools = Vec::with_capacity(worlds.len());
+    let mut junk_pools = Vec::with_capacity(worlds.len());
+    for (seed_info, check_map) in worlds.iter_mut().zip(check_maps.iter_mut()) {
+        info!("Preparing World for {}...", seed_info.player);
+        let (progression_pool, junk_pool) = prepare_world(rng, seed_info, check_map)?;
+        progression_pools.push(progression_pool);
+        junk_pools.push(junk_pool);
+    }
+
+    fill_progression(rng, worlds, check_maps, &mut progression_pools, &mut junk_pools)?;
+    fill_junk(rng, worlds, check_maps, junk_pools)?;
+
+    for (seed_info, check_map) in worlds.iter_mut().zip(check_maps.iter_mut()) {
+        finish_world(seed_info, check_map)?;
+    }
+
+    Ok(())
+}
+
+/// The shared Assumed Fill: the same as for a single world, but searching every world at once.
+fn fill_progression(
+    rng: &mut StdRng, worlds: &[SeedInfo], check_maps: &mut [CheckMap], progression_pools: &mut [Pool],
+    junk_pools: &mut [Pool],
+) -> Result<()> {
+    info!("Placing Progression Items...");
+
+    let mut placed_search = MultiworldSearch::new(worlds);
+    let mut major_maiamai_items = vec![0; worlds.len()];
+    let mut turn = 0;
+
+    while let Some(player) = (0..worlds.len())
+        .map(|offset| (turn + offset) % worlds.len())
+        .find(|&player| !progression_pools[player].is_empty())
+    {
+        let item = progression_pools[player].remove(0);
+        turn = player + 1;
+
+        // Resumed from what the items already placed can reach, as with `resume_assumed_search`
+        placed_search.collect_placed_items(worlds, check_maps);
+        placed_search.expand(worlds, check_maps);
+        let mut search = placed_search.clone();
+        for (reachability, pool) in search.reachabilities.iter_mut().zip(progression_pools.iter()) {
+            for item in pool {
+                reachability.add_item(*item);
+            }
+        }
+        search.expand(worlds, check_maps);
+
+        let own_world_only = is_dungeon_item(item) || is_dungeon_prize(item);
+        let reachable_checks = search
+            .reachabilities
+            .iter()
+            .zip(worlds)
+            .map(|(reachability, seed_info)| reachability.reachable_checks(seed_info))
+            .collect::<Vec<_>>();
+        let mut eligible_checks = Vec::new();
+        for (world, reachable_checks) in reachable_checks.iter().enumerate() {
+            if own_world_only && world != player {
+                continue;
+            }
+            let filtered_checks = filter_checks(item, reachable_checks, &worlds[world], &mut check_maps[world]);
+            eligible_checks.extend(filtered_checks.into_iter().map(|check| (world, check)));
+        }
+
+        if eligible_checks.is_empty() {
+            let searched_worlds = worlds
+                .iter()
+                .zip(check_maps.iter())
+                .zip(&reachable_checks)
+                .map(|((seed_info, check_map), reachable_checks)| (seed_info, check_map, reachable_checks.as_slice()))
+                .collect::<Vec<_>>();
+            return Err(Error::game(FillFailure::in_worlds(
+                item,
+                player,
+                &get_all_checks(&worlds[player]),
+                &searched_worlds,
+            )));
+        }
+
+        let (world, check) = eligible_checks[rng.gen_range(0..eligible_checks.len())];
+        let placed_item = if world == player { item.into() } else { Randomizable::Remote(worlds[player].player, item) };
+        check_maps[world].insert(check.get_name().to_owned(), Some(placed_item));
+        handle_maiamai_limit(
+            placed_item,
+            check.get_name(),
+            &mut major_maiamai_items[world],
+            &worlds[world],
+            &mut check_maps[world],
+            &mut junk_pools[world],
+        );
+    }
+
+    Ok(())
+}
+
+/// Places the junk items of every player on the checks left empty in every world.
+fn fill_junk(rng: &mut StdRng, worlds: &[SeedInfo], check_maps: &mut [CheckMap], junk_pools: Vec<Pool>) -> Result<()> {
+    info!("Placing Junk Items...");
+
+    let mut empty_checks = Vec::new();
+    for (world, check_map) in check_maps.iter().enumerate() {
+        for (check_name, item) in check_map {
+            if item.is_none() {
+                empty_checks.push((world, check_name.clone()));
+            }
+        }
+    }
+
+    let junk_count = junk_pools.iter().map(Vec::len).sum::<usize>();
+    if empty_checks.len() < junk_count {
+        let (player, junk) = junk_pools
+            .iter()
+            .enumerate()
+            .flat_map(|(player, junk_pool)| junk_pool.iter().map(move |junk| (player, junk)))
+            .nth(empty_checks.len())
+            .unwrap();
+        let total_checks = check_maps.iter().map(|check_map| check_map.len()).sum();
+        return Err(Error::game(FillFailure::out_of_checks(
+            format!("{}'s {:?}", worlds[player].player, junk),
+            total_checks,
+        )));
+    } else if empty_checks.len() > junk_count {
+        return Err(Error::game(format!(
+            "Number of empty checks: {} does not match available junk items: {}",
+            empty_checks.len(),
+            junk_count
+        )));
+    }
+
+    // Traps spring on whoever finds them, so are never sent to another player
+    for (player, junk_pool) in junk_pools.into_iter().enumerate() {
+        for junk in traps::add_traps(rng, &worlds[player].settings, &junk_pool) {
+            let (world, check_name) = empty_checks.remove(rng.gen_range(0..empty_checks.len()));
+            let junk = match junk {
+                Randomizable::Item(item) if world != player => Randomizable::Remote(worlds[player].player, item),
+                junk => junk,
+            };
+            check_maps[world].insert(check_name, Some(junk));
+        }
+    }
+
+    Ok(())
+}
+
+/// A [`Reachability`] for every world, with the items found for other players handed over to them.
+#[derive(Clone)]
+struct MultiworldSearch<'s> {
+    reachabilities: Vec<Reachability<'s>>,
+
+    /// The checks whose items have already been handed over to another player, by world.
+    handed_over: HashSet<(usize, &'static str)>,
+}
+
+impl<'s> MultiworldSearch<'s> {
+    fn new(worlds: &'s [SeedInfo]) -> Self {
+        Self {
+            reachabilities: worlds.iter().map(|seed_info| Reachability::new(Progress::new(seed_info))).collect(),
+            handed_over: HashSet::new(),
+        }
+    }
+
+    /// Picks up any items placed at the accessible checks of every world since they were found.
+    fn collect_placed_items(&mut self, worlds: &[SeedInfo], check_maps: &[CheckMap]) {
+        for ((reachability, seed_info), check_map) in self.reachabilities.iter_mut().zip(worlds).zip(check_maps) {
+            reachability.collect_placed_items(seed_info, check_map);
+        }
+    }
+
+    /// Expands the search of every world until no more items can be found, for its own player or any other.
+    fn expand(&mut self, worlds: &[SeedInfo], check_maps: &[CheckMap]) {
+        loop {
+            for ((reachability, seed_info), check_map) in self.reachabilities.iter_mut().zip(worlds).zip(check_maps) {
+                reachability.expand(seed_info, check_map, |_| false);
+            }
+
+            let mut handed_over = Vec::new();
+            for (world, reachability) in self.reachabilities.iter().enumerate() {
+                for check in reachability.get_accessible_checks() {
+                    if let Some(Some(item @ Randomizable::Remote(player, _))) = check_maps[world].get(check.get_name())
+                    {
+                        if self.handed_over.insert((world, check.get_name())) {
+                            handed_over.push((*player, *item));
+                        }
+                    }
+                }
+            }
+
+            if handed_over.is_empty() {
+                break;
+            }
+
+            for (player, item) in handed_over {
+                self.reachabilities[player.index()].add_item(item);
+            }
+        }
+    }
+}
@@ -5,11 +5,22 @@ use serde::Serialize;
 pub struct Path {
     destination: Location,
     logic: Logic,
+    #[serde(skip)]
+    crack: bool,
 }
 
 impl Path {
     pub fn new(destination: Location, logic: Logic) -> Self {
-        Self { destination, logic }
+        Self { destination, logic, crack: false }
+    }
+
+    /// A path through a Crack, which redirects for shuffled doors must leave alone even if it shares their destination.
+    pub fn crack(destination: Location, logic: Logic) -> Self {
+        Self { destination, logic, crack: true }
+    }
+
+    pub fn is_crack(self) -> bool {
+        self.crack
     }
 
     pub fn get_destination(self) -> Location {
@@ -0,0 +1,225 @@
+use crate::filler;
+use crate::filler::check::Check;
+use crate::filler::cracks::{self, Crack};
+use crate::filler::filler_item::{Item, Vane};
+use crate::filler::item_pools::{self, Pool};
+use crate::filler::{filter_checks, is_dungeon_item, is_dungeon_prize, verify_accessibility, DUNGEON_PRIZE_CHECKS};
+use crate::{world, CheckMap, Error, Result, SeedInfo};
+use log::info;
+use modinfo::settings::{Cracksanity, WeatherVanes};
+use modinfo::Settings;
+use rand::rngs::StdRng;
+use std::collections::HashSet;
+
+/// Places the items pinned by the Plando onto their checks, ahead of the rest of the fill.
+///
+/// Pinned items are taken out of the item pools so they aren't placed twice. A pin is rejected if its check is already
+/// filled by another setting, or if the item isn't allowed there (e.g. a Small Key outside its dungeon).
+pub(super) fn place_plando_items(
+    seed_info: &SeedInfo, check_map: &mut CheckMap, progression: &mut Pool, junk: &mut Pool,
+) -> Result<()> {
+    if seed_info.settings.plando.items.is_empty() {
+        return Ok(());
+    }
+
+    info!("Placing Plando Items...");
+
+    for (check_name, item_name) in &seed_info.settings.plando.items {
+        let Some(check) = find_check(seed_info, check_name) else {
+            return Err(plando_error(format!("No check named \"{}\" was found.", check_name)));
+        };
+
+        if let Some(Some(placed)) = check_map.get(check_name) {
+            return Err(plando_error(format!(
+                "\"{}\" can't be pinned to \"{}\", it's already filled with \"{}\" by other settings.",
+                item_name,
+                check_name,
+                placed.as_str()
+            )));
+        }
+
+        let item = take_item(item_name, progression)
+            .or_else(|| take_item(item_name, junk))
+            .ok_or_else(|| plando_error(format!("No \"{}\" is left in the item pool to pin.", item_name)))?;
+
+        let prize_check = DUNGEON_PRIZE_CHECKS.contains(&check_name.as_str()) && !seed_info.settings.prizes_anywhere;
+        let conflict = if prize_check && !is_dungeon_prize(item) {
+            Some("only Dungeon Prizes can go there")
+        } else if is_dungeon_prize(item) && !prize_check && !seed_info.settings.prizes_anywhere {
+            Some("Dungeon Prizes must go on a dungeon's Prize check")
+        } else if is_dungeon_item(item) && filter_checks(item, &[check], seed_info, check_map).is_empty() {
+            Some("Keysanity doesn't allow it there")
+        } else {
+            None
+        };
+        if let Some(conflict) = conflict {
+            return Err(plando_error(format!(
+                "\"{}\" can't be pinned to \"{}\": {}.",
+                item_name, check_name, conflict
+            )));
+        }
+
+        check_map.insert(check_name.to_owned(), Some(item.into()));
+    }
+
+    verify_accessibility(seed_info, check_map, progression)
+        .map_err(|err| plando_error(format!("The pinned items leave the seed uncompletable: {}", err)))
+}
+
+/// Finds the item check with the given name, excluding quest checks.
+fn find_check(seed_info: &SeedInfo, check_name: &str) -> Option<Check> {
+    seed_info
+        .world_graph
+        .values()
+        .flat_map(|location_node| location_node.get_checks().iter().flatten())
+        .find(|check| check.get_name() == check_name && check.get_location_info().is_some())
+        .copied()
+}
+
+/// Removes the first item with the given name from the pool.
+fn take_item(item_name: &str, pool: &mut Pool) -> Option<Item> {
+    let index = pool.iter().position(|item| item.as_str() == item_name)?;
+    Some(pool.remove(index))
+}
+
+/// The Crack pairs pinned by the Plando. Under the Mirrored Cracksanity settings each pair brings its mirror image with
+/// it, just as when shuffled.
+pub(crate) fn get_crack_pins(settings: &Settings) -> Result<Vec<(Crack, Crack)>> {
+    let hyrule_up = item_pools::get_hyrule_up_cracks();
+    let lorule_up = item_pools::get_lorule_up_cracks();
+    let down = [item_pools::get_hyrule_down_cracks(), item_pools::get_lorule_down_cracks()].concat();
+    let all_cracks = [hyrule_up.as_slice(), lorule_up.as_slice(), down.as_slice()].concat();
+
+    let parse = |name: &str| {
+        all_cracks
+            .iter()
+            .copied()
+            .find(|crack| crack.as_str() == name)
+            .ok_or_else(|| plando_error(format!("No Crack named \"{}\" was found.", name)))
+    };
+
+    let mirrored =
+        matches!(settings.cracksanity, Cracksanity::MirroredCrossWorldPairs | Cracksanity::MirroredAnyWorldPairs);
+    let cross_world =
+        matches!(settings.cracksanity, Cracksanity::CrossWorldPairs | Cracksanity::MirroredCrossWorldPairs);
+
+    let mut pins = Vec::new();
+    for (a, b) in &settings.plando.cracks {
+        let (a, b) = (parse(a)?, parse(b)?);
+        pins.push((a, b));
+        if mirrored && a.get_mirror_crack() != b {
+            pins.push((a.get_mirror_crack(), b.get_mirror_crack()));
+        }
+    }
+
+    let mut pinned = HashSet::new();
+    for &(a, b) in &pins {
+        let vanilla = a.get_mirror_crack() == b;
+        let conflict = if a == b {
+            Some("a Crack can't lead to itself")
+        } else if !pinned.insert(a) || !pinned.insert(b) {
+            Some("one of them is already pinned")
+        } else if settings.cracksanity == Cracksanity::Off && !vanilla {
+            Some("Cracksanity is off")
+        } else if [a, b].iter().any(|crack| matches!(crack, Crack::DesertPalace | Crack::Zaganaga)) && !vanilla {
+            Some("the Desert Palace Cracks are never shuffled")
+        } else if down.contains(&a) != down.contains(&b) {
+            Some("only Cracks facing the same way can be paired")
+        } else if cross_world && a.get_world() == b.get_world() {
+            Some("Cracksanity only pairs Cracks in different worlds")
+        } else if [(a, b), (b, a)].iter().any(|&(a, b)| a == Crack::HyruleCastle && !lorule_up.contains(&b)) {
+            Some("the Hyrule Castle Crack must lead to a Lorule Crack facing the same way")
+        } else {
+            None
+        };
+
+        if let Some(conflict) = conflict {
+            return Err(plando_error(format!(
+                "Cracks \"{}\" and \"{}\" can't be paired: {}.",
+                a.as_str(),
+                b.as_str(),
+                conflict
+            )));
+        }
+    }
+
+    Ok(pins)
+}
+
+/// How many layouts of the unpinned Cracks are tried before the Plando's Crack pins are rejected.
+const CRACK_LAYOUT_ATTEMPTS: usize = 20;
+
+/// Reshuffles the Cracks left unpinned by the Plando until the pinned ones no longer cut part of the world off, as
+/// pairing e.g. the Hyrule Castle Crack elsewhere can leave another Crack as the only way into a region.
+pub(crate) fn connect_crack_pins(rng: &mut StdRng, seed_info: &mut SeedInfo) -> Result<()> {
+    if seed_info.settings.plando.cracks.is_empty() {
+        return Ok(());
+    }
+
+    for _ in 0..CRACK_LAYOUT_ATTEMPTS {
+        if filler::all_locations_accessible(rng, seed_info) {
+            return Ok(());
+        }
+
+        info!("Reshuffling Cracks around the Plando's pins...");
+        seed_info.crack_map = cracks::build_crack_map(&seed_info.settings, rng)?;
+        seed_info.world_graph =
+            world::build_world_graph(&seed_info.crack_map, &seed_info.dungeon_entrance_map, &Default::default());
+    }
+
+    if filler::all_locations_accessible(rng, seed_info) {
+        Ok(())
+    } else {
+        Err(plando_error(format!(
+            "The pinned Cracks leave part of the world unreachable in all {} layouts tried for the other Cracks.",
+            CRACK_LAYOUT_ATTEMPTS
+        )))
+    }
+}
+
+/// The Weather Vane pairs pinned by the Plando.
+pub(crate) fn get_vane_pins(settings: &Settings) -> Result<Vec<(Vane, Vane)>> {
+    let all_vanes = item_pools::get_weather_vanes();
+    let parse = |name: &str| {
+        all_vanes
+            .iter()
+            .copied()
+            .find(|vane| vane.as_str() == name)
+            .ok_or_else(|| plando_error(format!("No Weather Vane named \"{}\" was found.", name)))
+    };
+
+    let shuffled = settings.weather_vanes == WeatherVanes::Shuffled;
+
+    let mut pinned = HashSet::new();
+    let mut pins = Vec::new();
+    for (a, b) in &settings.plando.weather_vanes {
+        let (a, b) = (parse(a)?, parse(b)?);
+
+        let conflict = if !pinned.insert(a) || (a != b && !pinned.insert(b)) {
+            Some("one of them is already pinned")
+        } else if !shuffled && a != b {
+            Some("Weather Vanes aren't shuffled")
+        } else if shuffled && a == b {
+            Some("shuffled Weather Vanes always lead somewhere else")
+        } else {
+            None
+        };
+
+        if let Some(conflict) = conflict {
+            return Err(plando_error(format!(
+                "Weather Vanes \"{}\" and \"{}\" can't be paired: {}.",
+                a.as_str(),
+                b.as_str(),
+                conflict
+            )));
+        }
+
+        pins.push((a, b));
+    }
+
+    Ok(pins)
+}
+
+fn plando_error(message: String) -> Error {
+    Error::game(format!("Plando conflict: {}", message))
+}
@@ -1,11 +1,16 @@
+use crate::filler::bosses::{Boss, BossArena};
 use crate::filler::filler_item::Item::Quake;
 use crate::filler::filler_item::Vane;
 use crate::filler::filler_item::{Goal, Item, Randomizable};
 use crate::filler::item_pools;
-use crate::{DashSet, SeedInfo};
+use crate::filler::item_set::ItemSet;
+use crate::filler::start::Start;
+use crate::{PlayerId, SeedInfo};
+use modinfo::settings::boss_hunt::BossHunt;
 use modinfo::settings::cracks::Cracks;
 use modinfo::settings::cracksanity::Cracksanity;
 use modinfo::settings::keysy::Keysy;
+use modinfo::settings::maiamai_rewards::MaiamaiRewards;
 use modinfo::settings::nice_items::NiceItems;
 use modinfo::settings::ravios_shop::RaviosShop;
 use modinfo::settings::trials_door::TrialsDoor;
@@ -14,7 +19,7 @@ use modinfo::settings::{pedestal::PedestalSetting, Settings};
 
 #[derive(Clone, Debug)]
 pub struct Progress<'s> {
-    items: DashSet<Randomizable>,
+    items: ItemSet,
     seed_info: &'s SeedInfo,
 }
 
@@ -25,16 +30,22 @@ impl<'s> Progress<'s> {
 
     /// Constructs a new [`Progress`] instance that already has all minor progression items.
     pub fn nothing_but_hearts_and_rupees(seed_info: &'s SeedInfo) -> Progress<'s> {
-        let mut items = DashSet::default();
-        items.extend(item_pools::get_heart_pieces().iter().map(|&i| i.into()).collect::<DashSet<Randomizable>>());
-        items.extend(item_pools::get_heart_containers().iter().map(|&i| i.into()).collect::<DashSet<Randomizable>>());
-        items.extend(item_pools::get_gold_rupee_pool().iter().map(|&i| i.into()).collect::<DashSet<Randomizable>>());
-        items.extend(item_pools::get_silver_rupee_pool().iter().map(|&i| i.into()).collect::<DashSet<Randomizable>>());
-        items.extend(item_pools::get_purple_rupee_pool().iter().map(|&i| i.into()).collect::<DashSet<Randomizable>>());
-        Self { items, seed_info }
+        let mut progress = Self::new(seed_info);
+        for item in [
+            item_pools::get_heart_pieces(),
+            item_pools::get_heart_containers(),
+            item_pools::get_gold_rupee_pool(),
+            item_pools::get_silver_rupee_pool(),
+            item_pools::get_purple_rupee_pool(),
+        ]
+        .concat()
+        {
+            progress.add_item(item);
+        }
+        progress
     }
 
-    pub fn get_items(&self) -> &DashSet<Randomizable> {
+    pub fn get_items(&self) -> &ItemSet {
         &self.items
     }
 
@@ -42,44 +53,67 @@ impl<'s> Progress<'s> {
         &self.seed_info.settings
     }
 
-    pub fn add_item(&mut self, item: impl Into<Randomizable>) {
-        self.items.insert(item.into());
+    /// Where the player this [`Progress`] belongs to wakes up.
+    pub fn get_start(&self) -> Start {
+        self.seed_info.start
     }
 
-    pub fn difference(&self, other: &Progress) -> DashSet<Randomizable> {
-        let mut new_items: DashSet<Randomizable> = Default::default();
+    /// The player this [`Progress`] belongs to.
+    pub fn get_player(&self) -> PlayerId {
+        self.seed_info.player
+    }
+
+    /// Adds an item to this [`Progress`]. Items for other players of a Multiworld seed go to their own [`Progress`]
+    /// instead, and are ignored.
+    pub fn add_item(&mut self, item: impl Into<Randomizable>) {
+        match item.into() {
+            Randomizable::Remote(player, item) if player == self.get_player() => {
+                self.items.insert(item);
+            },
+            Randomizable::Remote(..) => {},
+            item => {
+                self.items.insert(item);
+            },
+        }
+    }
 
-        for item in &self.items {
-            if !other.items.contains(item) {
-                new_items.insert(*item);
-            }
+    /// Adds every item the other players of a Multiworld seed find for this player, as if they were already sent.
+    pub fn add_received_items(&mut self) {
+        for &item in &self.seed_info.received_items {
+            self.items.insert(item);
         }
+    }
+
+    /// Adds every item in `items` to this [`Progress`].
+    pub fn add_items(&mut self, items: &ItemSet) {
+        self.items.extend(items);
+    }
 
-        new_items
+    pub fn difference(&self, other: &Progress) -> ItemSet {
+        self.items.difference(&other.items)
     }
 
+    /// Whether this [`Progress`] has an item. It never has items for other players of a Multiworld seed.
     pub fn has(&self, item: impl Into<Randomizable>) -> bool {
-        self.items.contains(&item.into())
+        match item.into() {
+            Randomizable::Remote(player, item) => player == self.get_player() && self.items.contains(item),
+            item => self.items.contains(item),
+        }
     }
 
     fn has_either(&self, item1: impl Into<Randomizable>, item2: impl Into<Randomizable>) -> bool {
-        self.items.contains(&item1.into()) || self.items.contains(&item2.into())
+        self.items.contains(item1) || self.items.contains(item2)
     }
 
     fn has_both(&self, item1: impl Into<Randomizable>, item2: impl Into<Randomizable>) -> bool {
-        self.items.contains(&item1.into()) && self.items.contains(&item2.into())
+        self.items.contains(item1) && self.items.contains(item2)
     }
 
     fn has_any<T>(&self, items: impl IntoIterator<Item = T>) -> bool
     where
         T: Into<Randomizable>,
     {
-        for item in items {
-            if self.has(item.into()) {
-                return true;
-            }
-        }
-        false
+        items.into_iter().any(|item| self.has(item))
     }
 
     fn has_amount<T>(&self, amount: u8, items: impl IntoIterator<Item = T>) -> bool
@@ -93,28 +127,49 @@ impl<'s> Progress<'s> {
     where
         T: Into<Randomizable>,
     {
-        let mut sum: u8 = 0;
-        for item in items {
-            // fixme expensive clone
-            if self.clone().has(item.into()) {
-                sum += 1;
-            }
-        }
-        sum
+        items.into_iter().map(Into::into).filter(|&item: &Randomizable| self.has(item)).count() as u8
     }
 
+    /// Checks the player's total Hearts, accounting for the Starting Hearts and Heart Cap settings.
     pub fn hearts(&self, amount: f32) -> bool {
+        let settings = self.get_settings();
         let heart_containers = self.count(item_pools::get_heart_containers()) as f32;
         let heart_pieces = self.count(item_pools::get_heart_pieces()) as f32;
-        3.0 + heart_containers + (0.25 * heart_pieces) >= amount
+        let hearts = settings.starting_hearts as f32 + heart_containers + (0.25 * heart_pieces);
+        hearts.min(settings.heart_cap as f32) >= amount
     }
 
+    /// Whether enough Rupees have been found to spend `amount`, on top of buying every shop item.
     pub fn has_rupees(&self, amount: u16) -> bool {
+        let shop_spending = self.seed_info.shop_prices.values().sum::<u16>();
+
+        amount + shop_spending <= self.count_rupees()
+    }
+
+    /// The total worth of the Rupees found.
+    fn count_rupees(&self) -> u16 {
         let purples = self.count(item_pools::get_purple_rupee_pool());
         let silvers = self.count(item_pools::get_silver_rupee_pool());
         let golds = self.count(item_pools::get_gold_rupee_pool());
 
-        amount <= (purples as u16 * 50) + (silvers as u16 * 100) + (golds as u16 * 300)
+        (purples as u16 * 50) + (silvers as u16 * 100) + (golds as u16 * 300)
+    }
+
+    /// Whether enough Rupees have been found to buy the item at a shop check, along with every cheaper shop item.
+    ///
+    /// Buying only what logic allows never costs more than this, so the Rupees are always there for the next item.
+    pub fn can_buy_shop_item(&self, check: &str) -> bool {
+        let shop_prices = &self.seed_info.shop_prices;
+        let Some(&price) = shop_prices.get(check) else {
+            return true;
+        };
+        let spending = shop_prices
+            .iter()
+            .filter(|&(other, &other_price)| (other_price, other.as_str()) <= (price, check))
+            .map(|(_, &other_price)| other_price)
+            .sum::<u16>();
+
+        self.count_rupees() >= spending
     }
 
     pub fn has_lamp(&self) -> bool {
@@ -317,6 +372,31 @@ impl<'s> Progress<'s> {
         self.has_amount(amount, item_pools::get_maiamai_pool())
     }
 
+    /// Checks if Mother Maiamai's reward in the given `slot` (1-9, Bow through Sand Rod) can be obtained.
+    ///
+    /// Upgrades may be redeemed in any order, so logic must assume all other upgrades were redeemed first. Milestone
+    /// rewards are handed out in slot order, one for every 10 Maiamai.
+    pub fn can_get_maiamai_reward(&self, slot: u8) -> bool {
+        let upgradable_items: [fn(&Self) -> bool; 9] = [
+            Self::has_bow,
+            Self::has_boomerang,
+            Self::has_hookshot,
+            Self::has_hammer,
+            Self::has_bombs,
+            Self::has_fire_rod,
+            Self::has_ice_rod,
+            Self::has_tornado_rod,
+            Self::has_sand_rod,
+        ];
+
+        match self.seed_info.settings.maiamai_rewards {
+            MaiamaiRewards::Upgrades => self.has_90_maiamai() && upgradable_items[slot as usize - 1](self),
+            MaiamaiRewards::Milestones => {
+                self.has_maiamai(slot * 10) && upgradable_items[..slot as usize].iter().all(|has_item| has_item(self))
+            },
+        }
+    }
+
     pub fn has_smooth_gem(&self) -> bool {
         self.has(Item::SmoothGem)
     }
@@ -493,6 +573,24 @@ impl<'s> Progress<'s> {
 
     // BOSSES ----------------------------------------------------------------------------------------------------------
 
+    /// The boss fought in the given `arena`.
+    pub fn get_boss(&self, arena: BossArena) -> Boss {
+        self.seed_info.boss_map.get(&arena).copied().unwrap_or(arena.get_vanilla_boss())
+    }
+
+    /// Whether the boss fought in the given `arena` can be defeated, whichever boss Boss Shuffle put there.
+    pub fn can_defeat_boss_in(&self, arena: BossArena) -> bool {
+        match self.get_boss(arena) {
+            Boss::Moldorm => self.can_defeat_moldorm(),
+            Boss::GemesaurKing => self.can_defeat_gemesaur(),
+            Boss::Arrghus => self.can_defeat_arrghus(),
+            Boss::Knucklemaster => self.can_defeat_knucklemaster(),
+            Boss::Stalblind => self.can_defeat_stalblind(),
+            Boss::Grinexx => self.can_defeat_grinexx(),
+            Boss::Zaganaga => self.can_defeat_zaganaga(),
+        }
+    }
+
     /// Margomill
     pub fn can_defeat_margomill(&self) -> bool {
         self.has_bombs() || (self.has_tornado_rod() && self.can_attack_iceproof())
@@ -523,11 +621,23 @@ impl<'s> Progress<'s> {
         self.can_merge() && (self.has_master_sword() || (self.swordless_mode() && self.can_attack_bowproof()))
     }
 
+    /// Stalblind
+    pub fn can_defeat_stalblind(&self) -> bool {
+        self.can_merge() && self.can_attack()
+    }
+
     /// Grinexx
     pub fn can_defeat_grinexx(&self) -> bool {
         self.has_ice_rod()
     }
 
+    /// Zaganaga
+    pub fn can_defeat_zaganaga(&self) -> bool {
+        self.has_sand_rod()
+            && self.hearts(9.0)
+            && (self.has_master_sword() || (self.swordless_mode() && self.can_attack()))
+    }
+
     /// Dharkstare
     pub fn can_defeat_dharkstare(&self) -> bool {
         self.has_fire_rod()
@@ -799,12 +909,20 @@ impl<'s> Progress<'s> {
     }
 
     pub fn has_required_pendants(&self) -> bool {
-        self.has(Item::PendantOfWisdom)
-            && self.has(Item::PendantOfPower)
-            && match self.seed_info.settings.ped_requirement {
-                PedestalSetting::Vanilla => true,
-                PedestalSetting::Standard => self.has_pendant_of_courage(),
-            }
+        use Item::*;
+        match self.seed_info.settings.ped_requirement {
+            PedestalSetting::Vanilla => self.has_both(PendantOfWisdom, PendantOfPower),
+            PedestalSetting::Standard => {
+                self.has_both(PendantOfWisdom, PendantOfPower) && self.has_pendant_of_courage()
+            },
+            PedestalSetting::Count { pendants, portraits } => {
+                self.has_amount(pendants, [PendantOfPower, PendantOfWisdom, PendantOfCourage])
+                    && self.has_amount(
+                        portraits,
+                        [SageGulley, SageOren, SageSeres, SageOsfala, SageImpa, SageIrene, SageRosso],
+                    )
+            },
+        }
     }
 
     pub fn has_sage_gulley(&self) -> bool {
@@ -837,6 +955,10 @@ impl<'s> Progress<'s> {
 
     pub fn has_lc_requirement(&self) -> bool {
         use Item::*;
+        if self.seed_info.settings.boss_hunt == BossHunt::LoruleCastle {
+            return self.has_required_bosses();
+        }
+
         self.has_amount(
             self.seed_info.settings.lc_requirement,
             [SageGulley, SageOren, SageSeres, SageOsfala, SageImpa, SageIrene, SageRosso],
@@ -845,12 +967,25 @@ impl<'s> Progress<'s> {
 
     pub fn has_yuganon_requirement(&self) -> bool {
         use Item::*;
+        if self.seed_info.settings.boss_hunt == BossHunt::LoruleCastle {
+            return self.has_required_bosses();
+        }
+
         self.has_amount(
             self.seed_info.settings.yuganon_requirement,
             [SageGulley, SageOren, SageSeres, SageOsfala, SageImpa, SageIrene, SageRosso],
         )
     }
 
+    pub fn has_required_bosses(&self) -> bool {
+        self.seed_info.required_bosses.iter().all(|&boss| self.has(boss))
+    }
+
+    /// Whether the Boss Hunt ends the game, and every required boss has been defeated.
+    pub fn has_completed_boss_hunt(&self) -> bool {
+        self.seed_info.settings.boss_hunt == BossHunt::EndGame && self.has_required_bosses()
+    }
+
     pub fn has_saved_thief_girl(&self) -> bool {
         self.has(Goal::Stalblind)
     }
@@ -0,0 +1,202 @@
+use crate::filler::check::Check;
+use crate::filler::filler_item::{Item, Randomizable};
+use crate::filler::progress::Progress;
+use crate::filler::util::shuffle;
+use crate::filler::{
+    filter_checks, find_reachable_checks, get_items_from_reachable_checks, is_dungeon_prize, MAIAMAI_UPGRADE_CHECKS,
+};
+use crate::{CheckMap, SeedInfo};
+use log::info;
+use rand::{rngs::StdRng, Rng};
+use std::collections::HashSet;
+
+const MAX_MOVES: usize = 100;
+
+/// Progression Balancing
+///
+/// Moves major items out of the spheres past the first half of the `sphere_depth` target, swapping them with junk found
+/// as early as possible, until the playthrough fits within the target or no more major items can be moved. Items found
+/// late are what hold back the spheres after them, so the latest are moved first.
+///
+/// Moving an item onto a check reachable earlier than the one it came from only ever makes it available sooner, so
+/// everything that was reachable stays reachable and the seed stays beatable.
+pub(super) fn balance_progression(rng: &mut StdRng, seed_info: &SeedInfo, check_map: &mut CheckMap) {
+    info!("Balancing Progression...");
+
+    let sphere_depth = seed_info.settings.sphere_depth as usize;
+    let mut spheres = find_spheres(seed_info, check_map);
+    let initial_spheres = spheres.len();
+    let mut moves = 0;
+
+    while moves < MAX_MOVES {
+        if spheres.len() <= sphere_depth {
+            break;
+        }
+
+        let Some((from, to, item)) = choose_move(rng, seed_info, check_map, &spheres) else {
+            break;
+        };
+
+        let junk = check_map.insert(to.get_name().to_owned(), Some(item.into())).flatten();
+        check_map.insert(from.get_name().to_owned(), junk);
+        spheres = find_spheres(seed_info, check_map);
+        moves += 1;
+    }
+
+    info!("Balanced Playthrough from {} to {} Spheres, moving {} Items", initial_spheres, spheres.len(), moves);
+}
+
+/// Chooses a major item to move out of a late sphere, and the check to move it onto, if any. Items are taken from the
+/// latest sphere first, and moved onto a random check holding junk in the earliest sphere that has one the item is
+/// allowed on (e.g. a Small Key is only moved within its own dungeon, as Keysanity would have placed it).
+///
+/// The junk takes the major item's place. Junk is never required by logic, so moving it later changes nothing.
+fn choose_move(
+    rng: &mut StdRng, seed_info: &SeedInfo, check_map: &CheckMap, spheres: &[Vec<Check>],
+) -> Option<(Check, Check, Item)> {
+    let early_spheres = (seed_info.settings.sphere_depth as usize / 2).max(1);
+    let (to_sphere, destinations) = spheres
+        .iter()
+        .map(|sphere| get_junk_checks(seed_info, check_map, sphere))
+        .enumerate()
+        .find(|(_, checks)| !checks.is_empty())?;
+
+    for sphere in spheres.iter().skip(early_spheres.max(to_sphere + 1)).rev() {
+        for from in shuffle(rng, sphere.clone()) {
+            let Some(item) = get_movable_item(seed_info, check_map, &from) else {
+                continue;
+            };
+
+            // The destinations are treated as empty, as they would be to the fill
+            let mut emptied = destinations.iter().map(|check| (check.get_name().to_owned(), None)).collect();
+            let allowed = filter_checks(item, &destinations, seed_info, &mut emptied);
+            if !allowed.is_empty() {
+                let to = allowed[rng.gen_range(0..allowed.len())];
+                return Some((from, to, item));
+            }
+        }
+    }
+
+    None
+}
+
+/// The checks in a sphere holding junk that a major item can be moved onto.
+fn get_junk_checks(seed_info: &SeedInfo, check_map: &CheckMap, sphere: &[Check]) -> Vec<Check> {
+    sphere
+        .iter()
+        .filter(|check| check.get_location_info().is_some())
+        .filter(|check| !MAIAMAI_UPGRADE_CHECKS.contains(&check.get_name()))
+        .filter(|check| !seed_info.full_exclusions.contains(check.get_name()))
+        .filter(|check| !seed_info.settings.plando.items.contains_key(check.get_name()))
+        .filter(|check| matches!(check_map[check.get_name()], Some(Randomizable::Item(item)) if is_junk(item)))
+        .copied()
+        .collect()
+}
+
+/// The major item on the given check, unless it's a quest item, a Dungeon Prize, or was placed there by a setting (e.g.
+/// Ravio's Shop, the Maiamai Upgrades or the Plando) and must stay.
+fn get_movable_item(seed_info: &SeedInfo, check_map: &CheckMap, check: &Check) -> Option<Item> {
+    let name = check.get_name();
+    if check.get_location_info().is_none()
+        || name.starts_with("Ravio's")
+        || MAIAMAI_UPGRADE_CHECKS.contains(&name)
+        || seed_info.settings.plando.items.contains_key(name)
+    {
+        return None;
+    }
+
+    match *check_map.get(name)? {
+        Some(Randomizable::Item(item)) if Randomizable::Item(item).is_major_item() => {
+            if is_dungeon_prize(item) || item == Item::BowOfLight && seed_info.settings.bow_of_light_in_castle {
+                None
+            } else {
+                Some(item)
+            }
+        },
+        _ => None,
+    }
+}
+
+/// Items from the junk pool, which no logic requires.
+fn is_junk(item: Item) -> bool {
+    matches!(item, Item::RupeeBlue | Item::RupeeRed | Item::MonsterTail | Item::MonsterHorn | Item::MonsterGuts)
+}
+
+/// Groups every reachable check by the sphere of the playthrough it first becomes reachable in. Spheres the spoiler log's
+/// playthrough would hide for only holding minor progression are merged into the next, so the number of spheres
+/// matches `Metrics::spheres`.
+fn find_spheres(seed_info: &SeedInfo, check_map: &mut CheckMap) -> Vec<Vec<Check>> {
+    let mut progress = Progress::new(seed_info);
+    let mut seen_checks = HashSet::new();
+    let mut spheres = Vec::new();
+    let mut sphere = Vec::new();
+
+    loop {
+        let reachable_checks = find_reachable_checks(seed_info, &progress);
+        let reachable_items = get_items_from_reachable_checks(seed_info, &reachable_checks, check_map);
+        let new_items = reachable_items.difference(&progress);
+
+        if new_items.is_empty() {
+            break;
+        }
+
+        progress.add_items(&new_items);
+        sphere.extend(reachable_checks.into_iter().filter(|check| seen_checks.insert(check.get_name())));
+
+        let has_playthrough_item = sphere.iter().any(|check| match *check_map.get(check.get_name()).unwrap() {
+            Some(item) => item.include_in_sphere_search() && new_items.contains(item),
+            None => false,
+        });
+        if has_playthrough_item {
+            spheres.push(std::mem::take(&mut sphere));
+        }
+    }
+
+    spheres
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::filler::{self, is_dungeon_item};
+    use crate::{build_world, PlayerId, SeedHash};
+    use modinfo::settings::{FillAlgorithm, Plando};
+    use modinfo::Settings;
+    use rand::SeedableRng;
+    use std::collections::BTreeMap;
+
+    /// Balancing may only move items where the fill could have put them, and must leave the Plando's pins alone.
+    #[test]
+    fn keeps_fill_restrictions() {
+        let settings = Settings {
+            fill_algorithm: FillAlgorithm::ProgressionBalancing,
+            sphere_depth: 4,
+            plando: Plando {
+                items: BTreeMap::from([("[TR] (1F) Grate Chest".to_owned(), "Pegasus Boots".to_owned())]),
+                ..Default::default()
+            },
+            ..serde_json::from_str("{}").unwrap()
+        };
+
+        for seed in 1..=3 {
+            let rng = &mut StdRng::seed_from_u64(seed as u64);
+            let hash = SeedHash::new(seed, &settings);
+            let mut seed_info = build_world(seed, settings.clone(), hash, PlayerId::default(), rng).unwrap();
+            let check_map = &mut filler::prefill_check_map(&mut seed_info.world_graph);
+            filler::fill_all_locations_reachable(rng, &mut seed_info, check_map).unwrap();
+
+            let checks = seed_info.world_graph.values().flat_map(|node| node.get_checks().iter().flatten());
+            for &check in checks.filter(|check| check.get_location_info().is_some()) {
+                let Some(Randomizable::Item(item)) = check_map[check.get_name()] else {
+                    continue;
+                };
+                if is_dungeon_item(item) || is_dungeon_prize(item) {
+                    let emptied = &mut [(check.get_name().to_owned(), None)].into_iter().collect();
+                    let allowed = filter_checks(item, &[check], &seed_info, emptied);
+                    assert!(!allowed.is_empty(), "Seed {}: {:?} was moved onto {}", seed, item, check.get_name());
+                }
+            }
+            assert_eq!(check_map["[TR] (1F) Grate Chest"], Some(Randomizable::Item(Item::PegasusBoots)));
+        }
+    }
+}
@@ -0,0 +1,413 @@
+use crate::filler::check::Check;
+use crate::filler::cracks::Crack;
+use crate::filler::filler_item::{Goal, Item, Randomizable, Vane};
+use crate::filler::get_check_items;
+use crate::filler::location::Location;
+use crate::filler::logic::Logic;
+use crate::filler::path::Path;
+use crate::filler::progress::Progress;
+use crate::{CheckMap, SeedInfo};
+use macros::fail;
+use queue::Queue;
+use std::collections::HashSet;
+use strum::IntoEnumIterator;
+
+/// Everything reachable from the start with some [`Progress`], picking up the items found along the way.
+///
+/// Logic only ever becomes satisfiable as items are added, so anything found accessible stays accessible and only what
+/// is blocked needs evaluating again once new items are found. This also means a search can be cloned and expanded
+/// with more items, without repeating the work already done.
+#[derive(Clone)]
+pub(crate) struct Reachability<'s> {
+    progress: Progress<'s>,
+    visited: HashSet<Location>,
+    loc_queue: Vec<Location>,
+    accessible_checks: Vec<Check>,
+    accessible_check_names: HashSet<&'static str>,
+    accessible_paths: HashSet<(Location, usize)>,
+    blocked_checks: Vec<Check>,
+    blocked_paths: Vec<(Location, usize, Path)>,
+}
+
+impl<'s> Reachability<'s> {
+    pub(crate) fn new(progress: Progress<'s>) -> Self {
+        let start_node = progress.get_start().get_location();
+        Self {
+            progress,
+            visited: HashSet::from([start_node]),
+            loc_queue: vec![start_node],
+            accessible_checks: Vec::new(),
+            accessible_check_names: HashSet::new(),
+            accessible_paths: HashSet::new(),
+            blocked_checks: Vec::new(),
+            blocked_paths: Vec::new(),
+        }
+    }
+
+    pub(crate) fn get_progress(&self) -> &Progress<'s> {
+        &self.progress
+    }
+
+    /// The locations reached so far.
+    pub(crate) fn get_visited(&self) -> &HashSet<Location> {
+        &self.visited
+    }
+
+    /// The checks found accessible so far, in the order they were found.
+    pub(crate) fn get_accessible_checks(&self) -> &[Check] {
+        &self.accessible_checks
+    }
+
+    /// Whether `check` has been found accessible.
+    pub(crate) fn is_accessible(&self, check: &Check) -> bool {
+        self.accessible_check_names.contains(check.get_name())
+    }
+
+    /// What keeps `check` in the given `location` from being accessible: the requirements of the paths into that
+    /// location the search couldn't take, or the check's own requirements if the location was reached.
+    pub(crate) fn describe_blocked(&self, location: Location, check: &Check) -> String {
+        if self.visited.contains(&location) {
+            return format!("in {:?}, {}", location, self.describe_unmet(check.get_logic()));
+        }
+
+        let mut paths = self
+            .blocked_paths
+            .iter()
+            .filter(|(_, _, path)| path.get_destination() == location)
+            .map(|(origin, _, path)| format!("from {:?}, {}", origin, self.describe_unmet(path.get_logic())))
+            .collect::<Vec<_>>();
+        paths.dedup();
+        if paths.is_empty() {
+            format!("{:?} is only connected to other unreachable locations", location)
+        } else {
+            format!("no path into {:?} is open: {}", location, paths.join("; "))
+        }
+    }
+
+    /// Names what `logic` is missing from this search's progress: the items and events that would each satisfy it, or
+    /// failing that, one smallest combination of them that would.
+    ///
+    /// Logic can't be inspected, so the items and events not yet found are tried against it instead.
+    fn describe_unmet(&self, logic: Logic) -> String {
+        let items = self.progress.get_items();
+        let candidates = Item::iter()
+            .map(Randomizable::from)
+            .chain(Goal::iter().map(Randomizable::from))
+            .chain(Vane::iter().map(Randomizable::from))
+            .chain(Crack::iter().map(Randomizable::from))
+            .filter(|&candidate| !items.contains(candidate))
+            .collect::<Vec<_>>();
+        let satisfied_by = |extra: &[Randomizable]| {
+            let mut progress = self.progress.clone();
+            extra.iter().for_each(|&item| progress.add_item(item));
+            logic.can_access(&progress)
+        };
+        // Copies of a progressive item share a name, so are only listed once as alternatives
+        let mut alternatives = candidates
+            .iter()
+            .filter(|&&candidate| satisfied_by(&[candidate]))
+            .map(Randomizable::to_string)
+            .collect::<Vec<_>>();
+        alternatives.dedup();
+        match alternatives.len() {
+            0 => {},
+            1 => return format!("the logic needs {}", alternatives[0]),
+            _ => return format!("the logic needs one of: {}", alternatives.join(", ")),
+        }
+        if !satisfied_by(&candidates) {
+            return "the logic can't be met by any items or events".to_owned();
+        }
+
+        // Drop every candidate the logic can do without, leaving one combination it can't
+        let mut needed = candidates;
+        let mut i = 0;
+        while i < needed.len() {
+            let item = needed.remove(i);
+            if !satisfied_by(&needed) {
+                needed.insert(i, item);
+                i += 1;
+            }
+        }
+        let mut counted: Vec<(String, usize)> = Vec::new();
+        for name in needed.iter().map(Randomizable::to_string) {
+            match counted.last_mut() {
+                Some((last, count)) if *last == name => *count += 1,
+                _ => counted.push((name, 1)),
+            }
+        }
+        let names = counted
+            .into_iter()
+            .map(|(name, count)| if count == 1 { name } else { format!("{} x{}", name, count) })
+            .collect::<Vec<_>>();
+        format!("the logic needs all of: {}", names.join(", "))
+    }
+
+    pub(crate) fn add_item(&mut self, item: impl Into<Randomizable>) {
+        self.progress.add_item(item);
+    }
+
+    /// Picks up any items placed at the accessible checks since they were found.
+    pub(crate) fn collect_placed_items(&mut self, seed_info: &SeedInfo, check_map: &CheckMap) {
+        for check in &self.accessible_checks {
+            for item in get_check_items(seed_info, check, check_map) {
+                self.progress.add_item(item);
+            }
+        }
+    }
+
+    /// Expands the search until no more items can be found. Checks matching `ignore` are treated as never accessible.
+    pub(crate) fn expand(&mut self, seed_info: &SeedInfo, check_map: &CheckMap, ignore: impl Fn(&Check) -> bool) {
+        let Self {
+            progress,
+            visited,
+            loc_queue,
+            accessible_checks,
+            accessible_check_names,
+            accessible_paths,
+            blocked_checks,
+            blocked_paths,
+        } = self;
+
+        let mut access_check = |check: &Check, progress: &mut Progress| {
+            accessible_checks.push(*check);
+            accessible_check_names.insert(check.get_name());
+            for item in get_check_items(seed_info, check, check_map) {
+                progress.add_item(item);
+            }
+        };
+
+        loop {
+            let items_before = *progress.get_items();
+
+            // Retry everything blocked, as new items may have been found since it was last evaluated
+            blocked_checks.retain(|check| {
+                if !check.can_access(progress) {
+                    return true;
+                }
+                access_check(check, progress);
+                false
+            });
+            blocked_paths.retain(|&(location, index, path)| {
+                if visited.contains(&path.get_destination()) {
+                    return false;
+                } else if !path.can_access(progress) {
+                    return true;
+                }
+                accessible_paths.insert((location, index));
+                visited.insert(path.get_destination());
+                loc_queue.push(path.get_destination());
+                false
+            });
+
+            while let Some(location) = loc_queue.pop() {
+                let location_node = match seed_info.world_graph.get(&location) {
+                    Some(loc) => loc,
+                    None => {
+                        fail!("Location Undefined: {:?}", location);
+                    },
+                };
+
+                for check in location_node.get_checks().iter().flatten().filter(|&check| !ignore(check)) {
+                    if check.can_access(progress) {
+                        access_check(check, progress);
+                    } else {
+                        blocked_checks.push(*check);
+                    }
+                }
+
+                for (index, path) in location_node.get_paths().iter().flatten().enumerate() {
+                    let destination = path.get_destination();
+                    if visited.contains(&destination) {
+                        continue;
+                    } else if path.can_access(progress) {
+                        accessible_paths.insert((location, index));
+                        visited.insert(destination);
+                        loc_queue.push(destination);
+                    } else {
+                        blocked_paths.push((location, index, *path));
+                    }
+                }
+            }
+
+            if *progress.get_items() == items_before {
+                break;
+            }
+        }
+    }
+
+    /// All accessible checks, listed in the order [`find_reachable_checks`] would give for the current [`Progress`].
+    /// The logic results already known are reused rather than evaluated again.
+    ///
+    /// [`find_reachable_checks`]: crate::filler::find_reachable_checks
+    pub(crate) fn reachable_checks(&self, seed_info: &SeedInfo) -> Vec<Check> {
+        traverse_world_graph(
+            seed_info,
+            |check| self.accessible_check_names.contains(check.get_name()),
+            |location, index, path| {
+                self.accessible_paths.contains(&(location, index)) || path.can_access(&self.progress)
+            },
+        )
+    }
+}
+
+/// Breadth-first traversal of the World Graph from the start, returning every accessible check in the order found.
+///
+/// Paths are identified to `can_take_path` by their origin and their index amongst that Location's paths.
+pub(crate) fn traverse_world_graph(
+    SeedInfo { world_graph, start, .. }: &SeedInfo, mut can_access_check: impl FnMut(&Check) -> bool,
+    mut can_take_path: impl FnMut(Location, usize, &Path) -> bool,
+) -> Vec<Check> {
+    let start_node = start.get_location();
+    let mut loc_queue: Queue<Location> = Queue::from(vec![start_node]);
+    let mut visited: HashSet<Location> = HashSet::new();
+    let mut reachable_checks: Vec<Check> = Vec::new();
+
+    visited.insert(start_node);
+
+    while let Some(location) = loc_queue.dequeue() {
+        // Grab the location from the map, verify it is defined
+        let location_node = match world_graph.get(&location) {
+            Some(loc) => loc,
+            None => {
+                fail!("Location Undefined: {:?}", location);
+            },
+        };
+
+        // Iterate over the location's checks
+        for check in location_node.get_checks().iter().flatten() {
+            if can_access_check(check) {
+                reachable_checks.push(*check);
+            }
+        }
+
+        // Queue new paths reachable from this location
+        for (index, path) in location_node.get_paths().iter().flatten().enumerate() {
+            let destination = path.get_destination();
+            if !visited.contains(&destination) && can_take_path(location, index, path) {
+                loc_queue.queue(destination).expect("TODO: panic message");
+                visited.insert(destination);
+            }
+        }
+    }
+
+    reachable_checks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::filler::{self, find_reachable_checks};
+    use crate::{build_world, PlayerId, SeedHash};
+    use modinfo::settings::{Cracksanity, DungeonEntrances, InteriorShuffle, LogicMode, WeatherVanes};
+    use modinfo::Settings;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    /// The incremental search must find exactly what searching the whole graph from scratch with
+    /// [`find_reachable_checks`] does, as items are picked up one at a time.
+    #[test]
+    fn matches_find_reachable_checks() {
+        let defaults: Settings = serde_json::from_str("{}").unwrap();
+        let settings = [
+            defaults.clone(),
+            Settings {
+                logic_mode: LogicMode::Hard,
+                cracksanity: Cracksanity::AnyWorldPairs,
+                weather_vanes: WeatherVanes::Shuffled,
+                ..defaults.clone()
+            },
+            Settings {
+                logic_mode: LogicMode::Glitched,
+                dungeon_entrances: DungeonEntrances::CrossWorld,
+                interior_shuffle: InteriorShuffle::Coupled,
+                ..defaults.clone()
+            },
+            Settings {
+                logic_mode: LogicMode::Hell,
+                cracksanity: Cracksanity::Decoupled,
+                weather_vanes: WeatherVanes::Shuffled,
+                decouple_weather_vanes: true,
+                interior_shuffle: InteriorShuffle::Decoupled,
+                ..defaults.clone()
+            },
+        ];
+
+        for settings in settings {
+            let rng = &mut StdRng::seed_from_u64(1);
+            let hash = SeedHash::new(1, &settings);
+            let mut seed_info = build_world(1, settings, hash, PlayerId::default(), rng).unwrap();
+            let check_map = &mut filler::prefill_check_map(&mut seed_info.world_graph);
+            filler::fill_all_locations_reachable(rng, &mut seed_info, check_map).unwrap();
+
+            let mut placed =
+                check_map.iter().filter_map(|(name, item)| item.map(|item| (name.clone(), item))).collect::<Vec<_>>();
+            placed.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+            let mut reachability = Reachability::new(Progress::new(&seed_info));
+            let mut owned = Progress::new(&seed_info);
+            for (_, item) in placed.iter().step_by(7) {
+                reachability.add_item(*item);
+                reachability.expand(&seed_info, check_map, |_| false);
+                owned.add_item(*item);
+
+                // Search from scratch until no new items are found
+                let mut progress = owned.clone();
+                let checks = loop {
+                    let checks = find_reachable_checks(&seed_info, &progress);
+                    let mut found = owned.clone();
+                    for check in &checks {
+                        for item in filler::get_check_items(&seed_info, check, check_map) {
+                            found.add_item(item);
+                        }
+                    }
+                    if found.get_items() == progress.get_items() {
+                        break checks;
+                    }
+                    progress = found;
+                };
+
+                let names = |checks: &[Check]| checks.iter().map(|check| check.get_name()).collect::<Vec<_>>();
+                assert_eq!(reachability.get_progress().get_items(), progress.get_items());
+                assert_eq!(names(&reachability.reachable_checks(&seed_info)), names(&checks));
+
+                let mut accessible = names(reachability.get_accessible_checks());
+                let mut expected = names(&checks);
+                accessible.sort();
+                expected.sort();
+                assert_eq!(accessible, expected);
+            }
+        }
+    }
+
+    /// Blocked checks are described by what they're missing, whether that's one of several items, a combination of
+    /// them, or the way into their location.
+    #[test]
+    fn describes_unmet_requirements() {
+        let settings: Settings = serde_json::from_str("{}").unwrap();
+        let rng = &mut StdRng::seed_from_u64(1);
+        let hash = SeedHash::new(1, &settings);
+        let mut seed_info = build_world(1, settings, hash, PlayerId::default(), rng).unwrap();
+        let check_map = &mut filler::prefill_check_map(&mut seed_info.world_graph);
+
+        let mut reachability = Reachability::new(Progress::new(&seed_info));
+        reachability.expand(&seed_info, check_map, |_| false);
+
+        let describe = |name: &str| {
+            let (location, check) = seed_info
+                .world_graph
+                .iter()
+                .flat_map(|(&location, node)| node.get_checks().iter().flatten().map(move |&check| (location, check)))
+                .find(|(_, check)| check.get_name() == name)
+                .unwrap();
+            assert!(!reachability.is_accessible(&check));
+            reachability.describe_blocked(location, &check)
+        };
+
+        assert_eq!(describe("[Mai] Wooden Bridge"), "in HyruleField, the logic needs Zora's Flippers");
+        assert_eq!(describe("Blacksmith"), "in BlacksmithHouse, the logic needs all of: Master Ore x2");
+        assert_eq!(
+            describe("House of Gales Island Ghost"),
+            "no path into HouseOfGalesIsland is open: from HyruleField, the logic needs Zora's Flippers"
+        );
+    }
+}
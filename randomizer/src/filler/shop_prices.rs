@@ -0,0 +1,28 @@
+use modinfo::settings::shop_prices::ShopPrices;
+use modinfo::Settings;
+use rand::rngs::StdRng;
+use rand::Rng;
+use std::collections::BTreeMap;
+
+/// The checks whose items are bought with Rupees.
+const SHOP_CHECKS: [&str; 11] = [
+    "Ravio's Shop (1)", "Ravio's Shop (2)", "Ravio's Shop (3)", "Ravio's Shop (4)", "Ravio's Shop (5)",
+    "Ravio's Shop (6)", "Ravio's Shop (7)", "Ravio's Shop (8)", "Ravio's Shop (9)", "Street Merchant (Left)",
+    "Street Merchant (Right)",
+];
+
+/// Chooses the price of every shop item, or none at all if shops keep their vanilla prices.
+pub(crate) fn choose_prices(settings: &Settings, rng: &mut StdRng) -> BTreeMap<String, u16> {
+    SHOP_CHECKS
+        .into_iter()
+        .filter_map(|check| {
+            let price = match settings.shop_prices {
+                ShopPrices::Vanilla => return None,
+                ShopPrices::Cheap => rng.gen_range(1..=5) * 10,
+                ShopPrices::Expensive => rng.gen_range(15..=30) * 10,
+                ShopPrices::Random => rng.gen_range(settings.shop_price_min..=settings.shop_price_max),
+            };
+            Some((check.to_owned(), price))
+        })
+        .collect()
+}
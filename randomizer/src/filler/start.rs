@@ -0,0 +1,203 @@
+use crate::filler::cracks::Crack;
+use crate::filler::filler_item::Vane;
+use crate::filler::loading_zone_pair::{LoadingZone, LoadingZoneId, LoadingZoneId::*, LoadingZones};
+use crate::filler::location::Location;
+use crate::filler::progress::Progress;
+use crate::SeedInfo;
+use game::Course::IndoorLight;
+use modinfo::settings::start_location::StartLocation;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rom::scene::SpawnPoint;
+use serde::{Serialize, Serializer};
+use std::collections::{HashMap, HashSet};
+
+/// A place Link can wake up at the start of the game.
+///
+/// Each is a spawn point the game already uses, for a door or crack that lets out onto a part of the overworld Link
+/// can't be stranded in.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Start {
+    // --- Hyrule --- //
+    #[default]
+    RaviosShop,
+    YourHouse,
+    KakarikoVillage,
+    WitchsHouse,
+    EasternPalace,
+    DesertPalace,
+    DeathMountainHyrule,
+    LakeHylia,
+
+    // --- Lorule --- //
+    VacantHouse,
+    ThievesTown,
+    SkullWoods,
+    MiseryMire,
+    TreacherousTower,
+    Graveyard,
+    DeathMountainLorule,
+    LoruleLake,
+}
+
+impl Start {
+    pub const ALL: [Self; 16] = [
+        Self::RaviosShop,
+        Self::YourHouse,
+        Self::KakarikoVillage,
+        Self::WitchsHouse,
+        Self::EasternPalace,
+        Self::DesertPalace,
+        Self::DeathMountainHyrule,
+        Self::LakeHylia,
+        Self::VacantHouse,
+        Self::ThievesTown,
+        Self::SkullWoods,
+        Self::MiseryMire,
+        Self::TreacherousTower,
+        Self::Graveyard,
+        Self::DeathMountainLorule,
+        Self::LoruleLake,
+    ];
+
+    pub fn is_vanilla(&self) -> bool {
+        *self == Self::RaviosShop
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::RaviosShop => "Ravio's Shop",
+            Self::YourHouse => "Your House",
+            Self::KakarikoVillage => "Kakariko Village",
+            Self::WitchsHouse => "Witch's House",
+            Self::EasternPalace => "Eastern Palace",
+            Self::DesertPalace => "Desert Palace",
+            Self::DeathMountainHyrule => "Death Mountain (Hyrule)",
+            Self::LakeHylia => "Lake Hylia",
+            Self::VacantHouse => "Vacant House",
+            Self::ThievesTown => "Thieves' Town",
+            Self::SkullWoods => "Skull Woods",
+            Self::MiseryMire => "Misery Mire",
+            Self::TreacherousTower => "Treacherous Tower",
+            Self::Graveyard => "Graveyard (Lorule)",
+            Self::DeathMountainLorule => "Death Mountain (Lorule)",
+            Self::LoruleLake => "Lorule Lake",
+        }
+    }
+
+    /// The World Graph node this start lies in, where the logic's search begins.
+    pub(crate) fn get_location(self) -> Location {
+        match self {
+            Self::RaviosShop => Location::RavioShop,
+            Self::YourHouse | Self::KakarikoVillage | Self::WitchsHouse | Self::LakeHylia => Location::HyruleField,
+            Self::EasternPalace => Location::EasternRuinsUpper,
+            Self::DesertPalace => Location::DesertPalaceWeatherVane,
+            Self::DeathMountainHyrule => Location::DeathMountainBase,
+            Self::VacantHouse | Self::ThievesTown => Location::LoruleCastleArea,
+            Self::SkullWoods => Location::SkullWoodsOverworld,
+            Self::MiseryMire => Location::MiseryMire,
+            Self::TreacherousTower => Location::LoruleDeathWest,
+            Self::Graveyard => Location::LoruleGraveyard,
+            Self::DeathMountainLorule => Location::RossosOreMineLorule,
+            Self::LoruleLake => Location::LoruleLakeNorthWest,
+        }
+    }
+
+    /// The spawn point Link appears at, borrowed from the door or crack nearest this start.
+    pub fn get_spawn_point(self) -> SpawnPoint {
+        match self {
+            // The vanilla start, whose door isn't in the loading zone tables yet
+            Self::RaviosShop => SpawnPoint::new(IndoorLight, 1, 1),
+            Self::YourHouse => Crack::YourHouse.get_spawn_point(),
+            Self::KakarikoVillage => Self::door_spawn_point(LoadingZones::hyrule_entrances(), BEE_GUY_HOUSE),
+            Self::WitchsHouse => Self::door_spawn_point(LoadingZones::hyrule_entrances(), WITCHS_HUT),
+            Self::EasternPalace => Self::door_spawn_point(LoadingZones::dungeon_entrances(), EASTERN_PALACE),
+            Self::DesertPalace => Self::door_spawn_point(LoadingZones::dungeon_entrances(), DESERT_PALACE_MAIN),
+            Self::DeathMountainHyrule => Self::door_spawn_point(LoadingZones::hyrule_entrances(), DM_WV_CAVE),
+            Self::LakeHylia => Self::door_spawn_point(LoadingZones::hyrule_entrances(), LAKESIDE_ITEM_SHOP),
+            Self::VacantHouse => Crack::VacantHouse.get_spawn_point(),
+            Self::ThievesTown => Crack::ThievesTown.get_spawn_point(),
+            Self::SkullWoods => Crack::SkullWoodsPillar.get_spawn_point(),
+            Self::MiseryMire => Crack::MiseryMireExit.get_spawn_point(),
+            Self::TreacherousTower => Crack::DeathWestLorule.get_spawn_point(),
+            Self::Graveyard => Crack::GraveyardLedgeLorule.get_spawn_point(),
+            Self::DeathMountainLorule => Crack::RossosOreMineLorule.get_spawn_point(),
+            Self::LoruleLake => Crack::LoruleLake.get_spawn_point(),
+        }
+    }
+
+    /// The overworld spawn point outside the given door, where Link appears on leaving through it.
+    fn door_spawn_point(
+        (entrances, _): (HashMap<LoadingZoneId, LoadingZone>, HashMap<LoadingZoneId, LoadingZone>), door: LoadingZoneId,
+    ) -> SpawnPoint {
+        let zone = entrances.get(&door).unwrap_or_else(|| panic!("No loading zone for: {:?}", door));
+        SpawnPoint::new(zone.get_scene(), zone.get_scene_id() as i32, zone.get_spawn() as i32)
+    }
+
+    /// The Weather Vane this start lies beside, if any.
+    pub fn get_vane(self) -> Option<Vane> {
+        match self {
+            Self::YourHouse => Some(Vane::YourHouseWV),
+            Self::KakarikoVillage => Some(Vane::KakarikoVillageWV),
+            Self::WitchsHouse => Some(Vane::WitchsHouseWV),
+            Self::EasternPalace => Some(Vane::EasternPalaceWV),
+            Self::DesertPalace => Some(Vane::DesertPalaceWV),
+            Self::DeathMountainHyrule => Some(Vane::DeathMountainHyruleWV),
+            Self::VacantHouse => Some(Vane::VacantHouseWV),
+            Self::ThievesTown => Some(Vane::ThievesTownWV),
+            Self::SkullWoods => Some(Vane::SkullWoodsWV),
+            Self::MiseryMire => Some(Vane::MiseryMireWV),
+            Self::TreacherousTower => Some(Vane::TreacherousTowerWV),
+            Self::Graveyard => Some(Vane::GraveyardWV),
+            Self::DeathMountainLorule => Some(Vane::DeathMountainLoruleWV),
+            Self::RaviosShop | Self::LakeHylia | Self::LoruleLake => None,
+        }
+    }
+}
+
+impl Serialize for Start {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+/// Chooses where Link wakes up. Vanilla starts don't touch the RNG.
+///
+/// Only starts Link can walk back to Ravio's Shop from without any items are safe, so a start in the desert or in
+/// Lorule is only chosen when settings like open Cracks and Start with Merge leave a way out.
+pub(crate) fn choose_start(rng: &mut StdRng, seed_info: &SeedInfo) -> Start {
+    let candidates: Vec<Start> = match seed_info.settings.start_location {
+        StartLocation::Vanilla => return Start::RaviosShop,
+        StartLocation::WeatherVane => Start::ALL.into_iter().filter(|start| start.get_vane().is_some()).collect(),
+        StartLocation::Random => Start::ALL.to_vec(),
+    };
+    let candidates =
+        candidates.into_iter().filter(|&start| can_walk_to_ravios_shop(seed_info, start)).collect::<Vec<_>>();
+    *candidates.choose(rng).expect("Hyrule Field starts can always walk to Ravio's Shop")
+}
+
+/// Searches the World Graph from `start` with no items, looking for Ravio's Shop.
+fn can_walk_to_ravios_shop(seed_info: &SeedInfo, start: Start) -> bool {
+    let progress = Progress::new(seed_info);
+    let mut visited = HashSet::from([start.get_location()]);
+    let mut queue = vec![start.get_location()];
+
+    while let Some(location) = queue.pop() {
+        if location == Location::RavioShop {
+            return true;
+        }
+
+        for path in
+            seed_info.world_graph.get(&location).and_then(|node| node.get_paths().as_ref()).into_iter().flatten()
+        {
+            if path.can_access(&progress) && visited.insert(path.get_destination()) {
+                queue.push(path.get_destination());
+            }
+        }
+    }
+
+    false
+}
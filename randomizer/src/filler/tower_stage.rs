@@ -11,6 +11,20 @@ impl TowerStage {
     pub fn new(course: Course, stage: usize) -> Self {
         Self { course, stage }
     }
+
+    /// The name settings refer to this floor by, e.g. "Advanced 12F".
+    pub fn name(&self) -> String {
+        format!("{} {}F", self.course_name(), self.stage)
+    }
+
+    fn course_name(&self) -> &'static str {
+        match self.course {
+            EnemyAttackS => "Beginner",
+            EnemyAttackM => "Intermediate",
+            EnemyAttackL => "Advanced",
+            _ => panic!("Invalid course"),
+        }
+    }
 }
 
 impl Serialize for TowerStage {
@@ -18,13 +32,6 @@ impl Serialize for TowerStage {
     where
         S: Serializer,
     {
-        let course_name = match self.course {
-            EnemyAttackS => "Beginner    ",
-            EnemyAttackM => "Intermediate",
-            EnemyAttackL => "Advanced    ",
-            _ => panic!("Invalid course"),
-        };
-
-        serializer.serialize_str(&format!("{} {: >2}F", course_name, self.stage))
+        serializer.serialize_str(&format!("{: <12} {: >2}F", self.course_name(), self.stage))
     }
 }
@@ -0,0 +1,20 @@
+use crate::filler::filler_item::{Item, Randomizable};
+use modinfo::Settings;
+use rand::rngs::StdRng;
+use rand::seq::{index, IteratorRandom};
+
+/// Replaces the Trap Frequency's share of the `junk` about to be placed with traps of the enabled types.
+pub(crate) fn add_traps(rng: &mut StdRng, settings: &Settings, junk: &[Item]) -> Vec<Randomizable> {
+    let mut junk = junk.iter().map(|&item| item.into()).collect::<Vec<Randomizable>>();
+    if settings.trap_frequency == 0 {
+        return junk;
+    }
+
+    let trap_count = junk.len() * settings.trap_frequency as usize / 100;
+    for i in index::sample(rng, junk.len(), trap_count) {
+        let trap = settings.trap_types.iter().choose(rng).expect("Trap Types should not be empty");
+        junk[i] = Randomizable::Trap(*trap);
+    }
+
+    junk
+}
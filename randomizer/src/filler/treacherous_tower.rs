@@ -1,27 +1,45 @@
 use crate::filler::tower_stage::TowerStage;
 use crate::Result;
-use game::Course::{EnemyAttackL, EnemyAttackM, EnemyAttackS};
+use game::Course::{self, EnemyAttackL, EnemyAttackM, EnemyAttackS};
 use macros::fail;
+use modinfo::settings::tower_floor_pool::TowerFloorPool;
 use modinfo::Settings;
 use rand::rngs::StdRng;
 use rand::seq::SliceRandom;
 
-/// Choose which floors of Treacherous Tower to use
+/// Choose which floors of Treacherous Tower to use, in the order they're played
 pub fn choose_floors(settings: &Settings, rng: &mut StdRng) -> Result<Vec<TowerStage>> {
-    let mut chosen_floors = Vec::with_capacity(settings.treacherous_tower_floors);
+    let fixed_floors = parse_floors("Fixed", &settings.treacherous_tower_fixed_floors)?;
+    let excluded_floors = parse_floors("Excluded", &settings.treacherous_tower_excluded_floors)?;
+    let floor_count = if fixed_floors.is_empty() { settings.treacherous_tower_floors } else { fixed_floors.len() + 2 };
+    let mut chosen_floors = Vec::with_capacity(floor_count);
 
-    if settings.treacherous_tower_floors < 2 {
+    if floor_count < 2 {
         fail!("Treacherous Tower must have at least 2 floors.");
-    } else if settings.treacherous_tower_floors > 66 {
+    } else if floor_count > 66 {
         fail!("Treacherous Tower may have at most 66 floors.");
     }
 
-    // Choose (n - 2) random filler floors.
-    // Since Advanced floors drastically outnumber Intermediate and Beginner floors, use a weighted factor to keep the
-    // result set in roughly an even Advanced/Intermediate/Beginner split, if possible. This won't work as effectively
-    // with more than 15 floors as we'll run out of Beginner and Intermediate floors.
-    floors()
-        .choose_multiple_weighted(rng, settings.treacherous_tower_floors - 2, |(course, _)| match course {
+    if fixed_floors.is_empty() {
+        let pool = floors()
+            .into_iter()
+            .filter(|&(course, _)| in_pool(settings.treacherous_tower_pool, course))
+            .filter(|&(course, stage)| !excluded_floors.contains(&TowerStage::new(course, stage as usize)))
+            .collect::<Vec<_>>();
+        if pool.len() < floor_count - 2 {
+            fail!(
+                "Treacherous Tower needs {} random floors, but only {} are left in the {} pool after exclusions.",
+                floor_count - 2,
+                pool.len(),
+                settings.treacherous_tower_pool
+            );
+        }
+
+        // Choose (n - 2) random filler floors.
+        // Since Advanced floors drastically outnumber Intermediate and Beginner floors, use a weighted factor to keep
+        // the result set in roughly an even Advanced/Intermediate/Beginner split, if possible. This won't work as
+        // effectively with more than 15 floors as we'll run out of Beginner and Intermediate floors.
+        pool.choose_multiple_weighted(rng, floor_count - 2, |(course, _)| match course {
             EnemyAttackS => 16.0,
             EnemyAttackM => 48.0 / 13.0,
             EnemyAttackL => 1.0,
@@ -30,11 +48,18 @@ pub fn choose_floors(settings: &Settings, rng: &mut StdRng) -> Result<Vec<TowerS
         .unwrap()
         .for_each(|(course, stage)| chosen_floors.push(TowerStage::new(*course, *stage as usize)));
 
-    // Sort so we (theoretically) have an increasing difficulty curve
-    chosen_floors.sort();
+        // Sort so we (theoretically) have an increasing difficulty curve
+        chosen_floors.sort();
+    } else {
+        chosen_floors.extend(fixed_floors);
+    }
 
     // First floor is always an actual 1st Floor because I can't figure out how to set the Stage # in the MSBF commands :P
-    let first_course = [EnemyAttackS, EnemyAttackM, EnemyAttackL].choose(rng).unwrap();
+    let first_courses = [EnemyAttackS, EnemyAttackM, EnemyAttackL]
+        .into_iter()
+        .filter(|&course| in_pool(settings.treacherous_tower_pool, course))
+        .collect::<Vec<_>>();
+    let first_course = first_courses.choose(rng).unwrap();
     chosen_floors.insert(0, TowerStage::new(*first_course, 1));
 
     // Regular Moldorm is always the last floor
@@ -43,6 +68,73 @@ pub fn choose_floors(settings: &Settings, rng: &mut StdRng) -> Result<Vec<TowerS
     Ok(chosen_floors)
 }
 
+/// Rejects Fixed Floors that contradict the other Treacherous Tower settings, rather than silently ignoring them.
+pub fn validate_floors(settings: &Settings) -> Result<()> {
+    let fixed_floors = parse_floors("Fixed", &settings.treacherous_tower_fixed_floors)?;
+    let excluded_floors = parse_floors("Excluded", &settings.treacherous_tower_excluded_floors)?;
+    if fixed_floors.is_empty() {
+        return Ok(());
+    }
+
+    if settings.treacherous_tower_floors != fixed_floors.len() + 2 {
+        fail!(
+            "Treacherous Tower has {} Fixed Floors, so its Floors setting must be {} (counting the first floor and \
+            Moldorm), not {}.",
+            fixed_floors.len(),
+            fixed_floors.len() + 2,
+            settings.treacherous_tower_floors
+        );
+    }
+
+    for floor in &fixed_floors {
+        if excluded_floors.contains(floor) {
+            fail!("Treacherous Tower Floor \"{}\" cannot be both fixed and excluded.", floor.name());
+        }
+
+        if !in_pool(settings.treacherous_tower_pool, floor.course) {
+            fail!(
+                "Treacherous Tower Fixed Floor \"{}\" is not in the {} pool.",
+                floor.name(),
+                settings.treacherous_tower_pool
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Whether floors from `course` belong to the chosen pool.
+fn in_pool(pool: TowerFloorPool, course: Course) -> bool {
+    match pool {
+        TowerFloorPool::Mixed => true,
+        TowerFloorPool::Easy => matches!(course, EnemyAttackS | EnemyAttackM),
+        TowerFloorPool::Hard => course == EnemyAttackL,
+    }
+}
+
+/// Looks up floors named in the settings (e.g. "Advanced 12F") amongst the floors that can be chosen, keeping their
+/// order. The first floors and the Moldorm floor can't be named, as they're always played.
+fn parse_floors<'a>(setting: &str, names: impl IntoIterator<Item = &'a String>) -> Result<Vec<TowerStage>> {
+    let floors =
+        floors().into_iter().map(|(course, stage)| TowerStage::new(course, stage as usize)).collect::<Vec<_>>();
+    let mut parsed: Vec<TowerStage> = Vec::new();
+    for name in names {
+        let Some(floor) = floors.iter().find(|floor| floor.name().eq_ignore_ascii_case(name.trim())) else {
+            fail!(
+                "Invalid Treacherous Tower {} Floor: \"{}\" is not a floor that can be chosen. Floors are named like \
+                \"Beginner 3F\", \"Intermediate 10F\" or \"Advanced 25F\".",
+                setting,
+                name
+            );
+        };
+        if parsed.contains(floor) {
+            fail!("Invalid Treacherous Tower {} Floor: \"{}\" is listed more than once.", setting, name);
+        }
+        parsed.push(*floor);
+    }
+    Ok(parsed)
+}
+
 fn floors() -> Vec<(game::Course, u32)> {
     vec![
         (EnemyAttackS, 2),
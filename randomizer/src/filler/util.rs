@@ -43,3 +43,22 @@ where
 
     Ok(map)
 }
+
+/// Randomly map each entry from a Vec to a different entry, such that every entry is the destination of exactly one
+/// other. Unlike [`pair_randomly`] the resultant Map is not bidirectional.
+pub(crate) fn map_randomly<T>(rng: &mut StdRng, vec: Vec<T>) -> crate::Result<BTreeMap<T, T>>
+where
+    T: Eq + Hash + Clone + Ord,
+{
+    if vec.len() < 2 {
+        return Err(Error::internal("Cannot map entries in Vec with fewer than 2 entries."));
+    }
+
+    // Reshuffle until no entry maps to itself, which takes ~e attempts on average
+    loop {
+        let shuffled = shuffle(rng, vec.clone());
+        if vec.iter().zip(&shuffled).all(|(a, b)| a != b) {
+            return Ok(vec.into_iter().zip(shuffled).collect());
+        }
+    }
+}
@@ -1,5 +1,6 @@
 use crate::filler::item_pools;
-use crate::filler::util::pair_randomly;
+use crate::filler::plando;
+use crate::filler::util::{map_randomly, pair_randomly};
 use crate::VaneMap;
 use log::info;
 use modinfo::settings::weather_vanes::WeatherVanes;
@@ -10,8 +11,21 @@ use rand::rngs::StdRng;
 pub fn build_vanes_map(settings: &Settings, rng: &mut StdRng) -> crate::Result<VaneMap> {
     info!("Building Weather Vane Map...");
     let weather_vanes_keys = item_pools::get_weather_vanes();
-    match settings.weather_vanes {
-        WeatherVanes::Shuffled => pair_randomly(rng, item_pools::get_weather_vanes()),
-        _ => Ok(weather_vanes_keys.iter().copied().zip(item_pools::get_weather_vanes().iter().copied()).collect::<_>()),
+
+    // Plando pairs are set aside, the rest are shuffled around them
+    let pins = plando::get_vane_pins(settings)?;
+    let mut unpinned_vanes = item_pools::get_weather_vanes();
+    unpinned_vanes.retain(|vane| !pins.iter().any(|&(a, b)| *vane == a || *vane == b));
+
+    let mut vane_map = match settings.weather_vanes {
+        WeatherVanes::Shuffled if settings.decouple_weather_vanes => map_randomly(rng, unpinned_vanes)?,
+        WeatherVanes::Shuffled => pair_randomly(rng, unpinned_vanes)?,
+        _ => weather_vanes_keys.iter().copied().zip(item_pools::get_weather_vanes().iter().copied()).collect::<_>(),
+    };
+    for (a, b) in pins {
+        vane_map.insert(a, b);
+        vane_map.insert(b, a);
     }
+
+    Ok(vane_map)
 }
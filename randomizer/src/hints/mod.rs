@@ -2,14 +2,17 @@ use crate::filler::check::Check;
 use crate::filler::cracks::Crack;
 use crate::filler::filler_item::{Goal, Item, Randomizable};
 use crate::filler::progress::Progress;
+use crate::filler::reachability::Reachability;
 use crate::filler::util::shuffle;
-use crate::filler::{find_reachable_checks, get_items_from_reachable_checks};
+use crate::filler::{find_reachable_checks, get_check_items, get_items_from_reachable_checks};
 use crate::hints::formatting::name;
 use crate::patch::util::is_sage;
 use crate::{CheckMap, DashSet, SeedInfo};
 use game::ghosts::HintGhost;
 use log::{debug, info};
 use macros::fail;
+use modinfo::settings::accessibility::Accessibility;
+use modinfo::settings::boss_hunt::BossHunt;
 use modinfo::settings::cracksanity::Cracksanity;
 use modinfo::settings::NiceItems;
 use rand::seq::IteratorRandom;
@@ -47,7 +50,7 @@ pub(crate) trait Hint: Serialize {
 #[derive(Debug, Clone)]
 pub struct LocationHint {
     /// The hinted item
-    pub item: Item,
+    pub item: Randomizable,
 
     /// The specific [`Check`] containing the hinted item.
     pub check: Check,
@@ -98,7 +101,7 @@ impl Hint for LocationHint {
             &self.check.get_location_info().unwrap().name(),
             article,
             if article.is_empty() { "" } else { " " },
-            &self.item.as_str()
+            &self.item
         )
     }
 }
@@ -252,8 +255,8 @@ pub fn generate_hints(rng: &mut StdRng, seed_info: &mut SeedInfo, check_map: &mu
     info!("Generating Hints...");
     const NUM_TOTAL_HINTS: usize = 29;
 
-    //
-    let mut taken_checks = seed_info.full_exclusions.iter().cloned().collect();
+    // Excluded and unreachable checks are never hinted
+    let mut taken_checks = seed_info.full_exclusions.iter().chain(&seed_info.unreachable_checks).cloned().collect();
     let mut taken_ghosts = Vec::new();
 
     // let mut crack_hints =
@@ -269,7 +272,7 @@ pub fn generate_hints(rng: &mut StdRng, seed_info: &mut SeedInfo, check_map: &mu
 
     duplicate_hints(
         &mut taken_ghosts, &mut always_hints, &mut maiamai_hints, &mut path_hints, &mut sometimes_hints,
-        NUM_TOTAL_HINTS, rng,
+        NUM_TOTAL_HINTS, seed_info.settings.accessibility, rng,
     );
 
     let bow_of_light_hint = generate_bow_of_light_hint(seed_info, check_map);
@@ -304,52 +307,75 @@ fn generate_crack_hints(
     todo!()
 }
 
+#[allow(clippy::too_many_arguments)]
 fn duplicate_hints(
-    taken_ghosts: &mut [HintGhost], always_hints: &mut Vec<LocationHint>, maiamai_hints: &mut Vec<LocationHint>,
-    path_hints: &mut Vec<PathHint>, sometimes_hints: &mut Vec<LocationHint>, num_total_hints: usize, rng: &mut StdRng,
+    taken_ghosts: &mut [HintGhost], always_hints: &mut [LocationHint], maiamai_hints: &mut [LocationHint],
+    path_hints: &mut [PathHint], sometimes_hints: &mut [LocationHint], num_total_hints: usize,
+    accessibility: Accessibility, rng: &mut StdRng,
 ) {
-    assert_eq!(
-        taken_ghosts.len(),
-        num_total_hints,
-        "Only {} of the expected {} hint ghosts were taken",
-        taken_ghosts.len(),
-        num_total_hints
-    );
     let hint_count = always_hints.len() + maiamai_hints.len() + path_hints.len() + sometimes_hints.len();
-    assert_eq!(
-        hint_count, num_total_hints,
-        "Only {} of the expected {} hints were actually created",
-        hint_count, num_total_hints
-    );
+
+    // Beatable Only can leave too few hintable checks to fill every ghost
+    if accessibility == Accessibility::BeatableOnly && hint_count < num_total_hints {
+        debug!("Only {} of the expected {} hints could be created", hint_count, num_total_hints);
+    } else {
+        assert_eq!(
+            taken_ghosts.len(),
+            num_total_hints,
+            "Only {} of the expected {} hint ghosts were taken",
+            taken_ghosts.len(),
+            num_total_hints
+        );
+        assert_eq!(
+            hint_count, num_total_hints,
+            "Only {} of the expected {} hints were actually created",
+            hint_count, num_total_hints
+        );
+    }
+    assert_eq!(taken_ghosts.len(), hint_count, "Every hint should have taken exactly one Hint Ghost");
 
     // todo probably don't need to duplicate this
 
     let mut ghosts = HintGhost::iter().collect::<Vec<_>>();
     ghosts.retain(|ghost| !taken_ghosts.contains(ghost));
 
-    for hint in always_hints {
+    for hint in always_hints.iter_mut() {
         hint.ghosts.push(ghosts.remove(rng.gen_range(0..ghosts.len())));
     }
 
-    for hint in maiamai_hints {
+    for hint in maiamai_hints.iter_mut() {
         hint.ghosts.push(ghosts.remove(rng.gen_range(0..ghosts.len())));
     }
 
-    for hint in path_hints {
+    for hint in path_hints.iter_mut() {
         hint.ghosts.push(ghosts.remove(rng.gen_range(0..ghosts.len())));
     }
 
-    for hint in sometimes_hints {
+    for hint in sometimes_hints.iter_mut() {
         hint.ghosts.push(ghosts.remove(rng.gen_range(0..ghosts.len())));
     }
 
-    assert_eq!(ghosts.len(), 0, "There were leftover Hint Ghosts: {:?}", ghosts);
+    if hint_count == num_total_hints {
+        assert_eq!(ghosts.len(), 0, "There were leftover Hint Ghosts: {:?}", ghosts);
+    } else if hint_count > 0 {
+        // Repeat hints on the ghosts of the hints that couldn't be created, rather than leave them with vanilla text
+        let mut hint_ghosts = always_hints
+            .iter_mut()
+            .chain(maiamai_hints.iter_mut())
+            .chain(sometimes_hints.iter_mut())
+            .map(|hint| &mut hint.ghosts)
+            .chain(path_hints.iter_mut().map(|hint| &mut hint.ghosts))
+            .collect::<Vec<_>>();
+        for (i, ghost) in ghosts.into_iter().enumerate() {
+            hint_ghosts[i % hint_count].push(ghost);
+        }
+    }
 }
 
 /// Generates the Bow of Light Hint
 /// todo need a generic "find where item be at" function
 fn generate_bow_of_light_hint(
-    SeedInfo { settings, world_graph, .. }: &SeedInfo, check_map: &mut CheckMap,
+    SeedInfo { settings, world_graph, unreachable_checks, .. }: &SeedInfo, check_map: &mut CheckMap,
 ) -> Option<BowOfLightHint> {
     if settings.progressive_bow_of_light {
         return None;
@@ -359,12 +385,17 @@ fn generate_bow_of_light_hint(
         for &check in location_node.clone().get_checks().iter().flatten().collect::<Vec<&Check>>() {
             if let Randomizable::Item(item) = check_map.get(check.get_name()).unwrap().unwrap() {
                 if BowOfLight == item {
-                    return Some(BowOfLightHint { check });
+                    return (!unreachable_checks.contains(check.get_name())).then_some(BowOfLightHint { check });
                 }
             }
         }
     }
 
+    // In a Multiworld seed the Bow of Light may be in another player's world
+    if settings.players > 1 {
+        return None;
+    }
+
     panic!("Failed to generate Bow of Light Hint");
 }
 
@@ -479,7 +510,7 @@ fn generate_location_hint(check_name: &'static str, seed_info: &SeedInfo, check_
         })
         .collect::<Vec<_>>();
 
-    LocationHint { item: item.as_item().unwrap(), check, logical_ghosts, ghosts: vec![] }
+    LocationHint { item, check, logical_ghosts, ghosts: vec![] }
 }
 
 /**
@@ -527,6 +558,13 @@ fn generate_sometimes_hints(
 
     sometimes_checks.retain(|check| !taken_checks.contains(&check.to_string()));
 
+    // Partial Maiamai Madness leaves some Maiamai in place, don't waste hints on them
+    let maiamai = crate::filler::item_pools::get_maiamai_pool();
+    sometimes_checks.retain(|&check| match check_map.get(check) {
+        Some(Some(Randomizable::Item(item))) => !(check.starts_with("[Mai]") && maiamai.contains(item)),
+        _ => true,
+    });
+
     let mut sometimes_hints = Vec::new();
     let mut sometimes_hint_count = 0;
     loop {
@@ -555,7 +593,7 @@ fn generate_sometimes_hints(
 /**
  * Path Hints
  *
- * Generates up to 6 Path Hints for each Boss guarding a Sage Portrait.
+ * Generates up to 6 Path Hints for each Boss guarding a Sage Portrait, or for each Boss required by the Boss Hunt.
  *
  * A "Path Hint" is a hint that specifies the location of a "Path Item" that is required to reach
  * and defeat a certain Boss, according the chosen Logic Mode and Settings.
@@ -584,7 +622,10 @@ fn generate_path_hints(
     let mut extra_paths_needed = 0;
 
     for (goal, prize_loc) in bosses_and_prize_locations {
-        if is_sage(check_map.get(prize_loc).unwrap().unwrap()) {
+        if seed_info.unreachable_checks.contains(prize_loc) {
+            debug!("No Path Hints possible for unreachable Goal: {}", goal.as_str());
+            extra_paths_needed += 1;
+        } else if is_path_goal(seed_info, check_map, goal, prize_loc) {
             let mut potential_paths = get_potential_path_hints(rng, seed_info, check_map, taken_checks, goal);
 
             if let Some(chosen_path) = choose_path_hint(&mut potential_paths, taken_checks, taken_ghosts, rng) {
@@ -616,6 +657,15 @@ fn generate_path_hints(
     chosen_paths
 }
 
+/// Boss Hunts make the required bosses the goal, otherwise the bosses guarding Sage Portraits are.
+fn is_path_goal(seed_info: &SeedInfo, check_map: &CheckMap, goal: Goal, prize_loc: &str) -> bool {
+    if seed_info.settings.boss_hunt == BossHunt::Off {
+        is_sage(check_map.get(prize_loc).unwrap().unwrap())
+    } else {
+        seed_info.required_bosses.contains(&goal)
+    }
+}
+
 fn choose_path_hint(
     potential_paths: &mut Vec<PathHint>, taken_checks: &mut Vec<String>, taken_ghosts: &mut Vec<HintGhost>,
     rng: &mut StdRng,
@@ -646,6 +696,7 @@ fn find_checks_before_goal(
 ) -> DashSet<Check> {
     let goal = goal.into();
     let mut progress = Progress::new(seed_info);
+    progress.add_received_items();
     let mut reachable_checks: Vec<Check>;
     let mut potential_path_checks: DashSet<Check> = Default::default();
 
@@ -661,9 +712,7 @@ fn find_checks_before_goal(
             break;
         }
 
-        for new_item in &new_items {
-            progress.add_item(*new_item);
-        }
+        progress.add_items(&new_items);
     }
 
     potential_path_checks
@@ -673,7 +722,6 @@ fn find_checks_before_goal(
 fn get_potential_path_hints(
     rng: &mut StdRng, seed_info: &SeedInfo, check_map: &mut CheckMap, taken_checks: &mut [String], goal: Goal,
 ) -> Vec<PathHint> {
-    let mut reachable_checks: Vec<Check>;
     let mut potential_paths: Vec<PathHint> = Vec::new();
 
     let mut potential_path_checks = find_checks_before_goal(seed_info, check_map, goal);
@@ -689,53 +737,44 @@ fn get_potential_path_hints(
 
     // Always start with all hearts and rupees to prevent "weird" hints where items can be considered path for them in
     // in a way that confuses players and is rarely helpful.
-    let nothing_but_hearts_and_rupees = Progress::nothing_but_hearts_and_rupees(seed_info);
+    // Items found for this player in the other worlds of a Multiworld seed are assumed as well
+    let mut nothing_but_hearts_and_rupees = Progress::nothing_but_hearts_and_rupees(seed_info);
+    nothing_but_hearts_and_rupees.add_received_items();
 
     // Test candidate items to see if Boss can be defeated without them
     for check in potential_path_checks {
-        // Reset Progression.
+        // Reset Progression, then search everything reachable without the Potential Path Location.
         // Cloning is more efficient than constructing here because that constructor is fat. (and ugly)
-        let mut progress = nothing_but_hearts_and_rupees.clone();
+        let mut reachability = Reachability::new(nothing_but_hearts_and_rupees.clone());
+        reachability.expand(seed_info, check_map, |c| check.eq(c));
 
-        loop {
-            reachable_checks = find_reachable_checks(seed_info, &progress);
-
-            // Remove Potential Path Location
-            reachable_checks.retain(|c| check.ne(c));
-
-            let reachable_items = get_items_from_reachable_checks(seed_info, &reachable_checks, check_map);
-
-            let new_items = reachable_items.difference(&progress);
-
-            if new_items.is_empty() {
-                // Item could be Path if goal couldn't be reached without it
-                if !progress.has(goal) {
-                    let hint_locations = reachable_items
-                        .get_items()
-                        .iter()
-                        .filter_map(|&item| if let Randomizable::HintGhost(ghost) = item { Some(ghost) } else { None })
-                        .collect::<_>();
-
-                    let path_item = check_map
-                        .get(check.get_name())
-                        .expect("Path check should be in Check Map")
-                        .expect("Path check should have Path Item");
-
-                    potential_paths.push(PathHint {
-                        goal,
-                        check,
-                        ghosts: vec![],
-                        logical_ghosts: hint_locations,
-                        path_item,
-                    });
-                }
-                break;
-            }
+        // Item could be Path if goal couldn't be reached without it
+        if reachability.get_progress().has(goal) {
+            continue;
+        }
+
+        let reachable_checks = reachability.reachable_checks(seed_info);
 
-            for new_item in &new_items {
-                progress.add_item(*new_item);
+        // Hint Ghosts are listed in the order a hashed set of the reachable items gives them, as they are chosen from
+        // by position
+        let mut reachable_item_set = DashSet::default();
+        for reachable_check in &reachable_checks {
+            for item in get_check_items(seed_info, reachable_check, check_map) {
+                reachable_item_set.insert(item);
             }
         }
+
+        let hint_locations = reachable_item_set
+            .iter()
+            .filter_map(|&item| if let Randomizable::HintGhost(ghost) = item { Some(ghost) } else { None })
+            .collect::<_>();
+
+        let path_item = check_map
+            .get(check.get_name())
+            .expect("Path check should be in Check Map")
+            .expect("Path check should have Path Item");
+
+        potential_paths.push(PathHint { goal, check, ghosts: vec![], logical_ghosts: hint_locations, path_item });
     }
 
     shuffle(rng, potential_paths)
@@ -827,3 +866,97 @@ const POSSIBLE_PATH_ITEMS: [Item; 45] = [
     Bottle02, Bottle03, Bottle04, Lamp01, Lamp02, Glove01, Glove02, Net01, Net02, Mail01, Mail02, OreYellow, OreGreen,
     OreBlue, OreRed,
 ];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::filler::logic::Logic;
+    use crate::{calculate_seed_info, SeedHash};
+    use modinfo::Settings;
+    use rand::SeedableRng;
+    use strum::EnumCount;
+
+    fn location_hint(taken_ghosts: &mut Vec<HintGhost>, ghost: HintGhost) -> LocationHint {
+        taken_ghosts.push(ghost);
+        LocationHint {
+            item: Randomizable::Item(Lamp01),
+            check: Check::new("Test Check", Logic::free(), None, None),
+            logical_ghosts: vec![ghost],
+            ghosts: vec![ghost],
+        }
+    }
+
+    /// Every Hint Ghost gives out exactly one hint, and each hint is given out by two ghosts.
+    #[test]
+    fn every_ghost_gives_a_hint() {
+        let defaults: Settings = serde_json::from_str("{}").unwrap();
+        for settings in [defaults.clone(), Settings { accessibility: Accessibility::BeatableOnly, ..defaults }] {
+            for seed in 1..=3 {
+                let hash = SeedHash::new(seed, &settings);
+                let rng = &mut StdRng::seed_from_u64(seed as u64);
+                let Hints { path_hints, always_hints, maiamai_hints, sometimes_hints, .. } =
+                    calculate_seed_info(seed, settings.clone(), hash, rng).unwrap().hints;
+
+                let hint_ghosts = always_hints
+                    .iter()
+                    .chain(&maiamai_hints)
+                    .chain(&sometimes_hints)
+                    .map(|hint| &hint.ghosts)
+                    .chain(path_hints.iter().map(|hint| &hint.ghosts))
+                    .collect::<Vec<_>>();
+                assert!(hint_ghosts.iter().all(|ghosts| ghosts.len() == 2));
+
+                let mut ghosts = hint_ghosts.into_iter().flatten().copied().collect::<Vec<_>>();
+                ghosts.sort_by_key(|ghost| *ghost as usize);
+                assert_eq!(ghosts, HintGhost::iter().collect::<Vec<_>>());
+            }
+        }
+    }
+
+    /// Under Beatable Only, the ghosts of hints that couldn't be created repeat the hints that could.
+    #[test]
+    fn repeats_missing_hints_when_beatable_only() {
+        let mut taken_ghosts = vec![];
+        let mut always_hints = vec![
+            location_hint(&mut taken_ghosts, HintGhost::LostWoodsMaze1),
+            location_hint(&mut taken_ghosts, HintGhost::LostWoodsMaze2),
+        ];
+
+        duplicate_hints(
+            &mut taken_ghosts,
+            &mut always_hints,
+            &mut [],
+            &mut [],
+            &mut [],
+            29,
+            Accessibility::BeatableOnly,
+            &mut StdRng::seed_from_u64(1),
+        );
+
+        let mut ghosts = always_hints.iter().flat_map(|hint| hint.ghosts.iter().copied()).collect::<Vec<_>>();
+        assert!(always_hints.iter().all(|hint| hint.ghosts.len() == HintGhost::COUNT / 2));
+        ghosts.sort_by_key(|ghost| *ghost as usize);
+        assert_eq!(ghosts, HintGhost::iter().collect::<Vec<_>>());
+    }
+
+    #[test]
+    #[should_panic(expected = "Only 2 of the expected 29 hint ghosts were taken")]
+    fn requires_every_hint_when_all_locations() {
+        let mut taken_ghosts = vec![];
+        let mut always_hints = vec![
+            location_hint(&mut taken_ghosts, HintGhost::LostWoodsMaze1),
+            location_hint(&mut taken_ghosts, HintGhost::LostWoodsMaze2),
+        ];
+
+        duplicate_hints(
+            &mut taken_ghosts,
+            &mut always_hints,
+            &mut [],
+            &mut [],
+            &mut [],
+            29,
+            Accessibility::AllLocations,
+            &mut StdRng::seed_from_u64(1),
+        );
+    }
+}
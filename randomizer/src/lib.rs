@@ -1,7 +1,10 @@
 use crate::filler::filler_item::Vane;
 use crate::filler::tower_stage::TowerStage;
 use crate::filler::trials::TrialsConfig;
-use crate::filler::{cracks, text, treacherous_tower, trials, vanes};
+use crate::filler::{
+    boss_hunt, bosses, cracks, dungeon_entrances, interiors, plando, shop_prices, start, text, treacherous_tower,
+    trials, vanes,
+};
 use crate::world::WorldGraph;
 use crate::{
     constants::VERSION,
@@ -10,11 +13,23 @@ use crate::{
     patch::lms::msbf::MsbfKey,
     system::UserConfig,
 };
+use filler::bosses::{Boss, BossArena};
 use filler::cracks::Crack;
-use filler::filler_item::Randomizable;
+use filler::diagnostics::FillFailure;
+use filler::dungeon_entrances::DungeonEntrance;
+use filler::filler_item::{Goal, Randomizable};
+use filler::interiors::InteriorMap;
+use filler::start::Start;
 use game::Item::{self};
 use log::{debug, error, info};
 use macros::fail;
+use modinfo::settings::boss_hunt::{BossHunt, BOSS_HUNT_BOSSES};
+use modinfo::settings::compass_shuffle::CompassShuffle;
+use modinfo::settings::fill_algorithm::FillAlgorithm;
+use modinfo::settings::keysanity::Keysanity;
+use modinfo::settings::shop_prices::{ShopPrices, MAX_SHOP_PRICE};
+use modinfo::settings::weather_vanes::WeatherVanes;
+use modinfo::settings::{accessibility::Accessibility, pedestal::PedestalSetting, MaiamaiShuffle, MAX_PLAYERS};
 use modinfo::Settings;
 use patch::Patcher;
 use path_absolutize::*;
@@ -26,10 +41,12 @@ use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::hash::BuildHasherDefault;
 use std::{
     error::Error as StdError,
-    fs::File,
+    fmt,
+    fs::{self, File},
     hash::{Hash, Hasher},
     io::{self, Write},
     ops::Deref,
+    path::Path,
 };
 use twox_hash::XxHash64;
 
@@ -77,6 +94,11 @@ impl Error {
         &self.kind
     }
 
+    /// The item the filler couldn't place, if that's why this error happened.
+    pub fn fill_failure(&self) -> Option<&FillFailure> {
+        self.inner.downcast_ref()
+    }
+
     /// Converts this error into its inner value.
     pub fn into_inner(self) -> Box<dyn StdError + Send + Sync + 'static> {
         self.inner
@@ -127,6 +149,10 @@ impl LocationInfo {
         self.subregion.world()
     }
 
+    pub fn home_world(&self) -> regions::World {
+        self.subregion.home_world()
+    }
+
     pub fn region(&self) -> &'static str {
         self.subregion.name()
     }
@@ -140,6 +166,36 @@ impl LocationInfo {
     }
 }
 
+/// One of the players of a Multiworld seed. Single player seeds only have the first.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct PlayerId(u8);
+
+impl PlayerId {
+    /// The player at the given index, counting from 0.
+    pub const fn new(index: u8) -> Self {
+        Self(index)
+    }
+
+    pub fn index(self) -> usize {
+        self.0 as usize
+    }
+}
+
+impl fmt::Display for PlayerId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Player {}", self.0 + 1)
+    }
+}
+
+impl Serialize for PlayerId {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
 /// A world layout for the patcher.
 #[derive(Clone, Debug, Default, Serialize)]
 pub struct Layout {
@@ -249,7 +305,7 @@ where
             let ordered = self.0.iter().map(|(&k, &v)| (k, v)).collect::<BTreeMap<_, _>>();
             let mut map = ser.serialize_map(Some(ordered.len()))?;
             for (k, v) in ordered {
-                map.serialize_entry(k, v.as_str())?;
+                map.serialize_entry(k, &v)?;
             }
             map.end()
         }
@@ -317,25 +373,59 @@ pub struct SeedInfo {
 
     pub settings: Settings,
 
+    /// The player this world belongs to, in a Multiworld seed.
+    #[serde(skip)]
+    pub player: PlayerId,
+
+    /// The items this player is given by the other players of a Multiworld seed, found in their worlds.
+    #[serde(skip)]
+    pub received_items: Vec<filler::filler_item::Item>,
+
     /// The list of exclusions provided by the user in [`settings`], enhanced by the randomizer based on settings.
     #[serde(skip_deserializing)]
     pub full_exclusions: BTreeSet<String>,
 
+    /// Where Link wakes up, and where logic's search of the World Graph begins.
+    #[serde(skip_deserializing, skip_serializing_if = "Start::is_vanilla")]
+    pub start: Start,
+
     #[serde(skip_deserializing)]
     pub treacherous_tower_floors: Vec<TowerStage>,
 
+    /// The bosses that must be defeated to complete the Boss Hunt.
+    #[serde(skip_deserializing, skip_serializing_if = "Vec::is_empty")]
+    pub required_bosses: Vec<Goal>,
+
+    /// The price of every shop item, by check. Empty if shops keep their vanilla prices.
+    #[serde(skip_deserializing, skip_serializing_if = "BTreeMap::is_empty")]
+    pub shop_prices: BTreeMap<String, u16>,
+
     #[serde(skip_deserializing)]
     pub trials_config: TrialsConfig,
 
     #[serde(skip_deserializing)]
     pub layout: Layout,
 
+    /// Item checks that can never be reached. Only Beatable Only seeds have these.
+    #[serde(skip_deserializing, skip_serializing_if = "BTreeSet::is_empty")]
+    pub unreachable_checks: BTreeSet<String>,
+
     #[serde(skip_deserializing)]
     pub crack_map: CrackMap,
 
     #[serde(skip_deserializing, rename = "weather_vane_map")]
     pub vane_map: VaneMap,
 
+    #[serde(skip_deserializing, rename = "dungeon_entrances")]
+    pub dungeon_entrance_map: DungeonEntranceMap,
+
+    #[serde(skip_deserializing, rename = "interiors")]
+    pub interior_map: InteriorMap,
+
+    /// The boss fought in each arena. Empty if bosses aren't shuffled.
+    #[serde(skip_deserializing, rename = "bosses", skip_serializing_if = "BTreeMap::is_empty")]
+    pub boss_map: BossMap,
+
     #[serde(skip_deserializing)]
     pub metrics: Metrics,
 
@@ -362,20 +452,50 @@ impl Default for SeedInfo {
             version: "".to_owned(),
             hash: Default::default(),
             settings: Default::default(),
+            player: Default::default(),
+            received_items: Default::default(),
             full_exclusions: Default::default(),
+            start: Default::default(),
             crack_map: Default::default(),
             vane_map: Default::default(),
+            dungeon_entrance_map: Default::default(),
+            interior_map: Default::default(),
+            boss_map: Default::default(),
             layout: Default::default(),
+            unreachable_checks: Default::default(),
             metrics: Default::default(),
             hints: Default::default(),
             trials_config: Default::default(),
             world_graph: Default::default(),
             treacherous_tower_floors: Default::default(),
+            required_bosses: Default::default(),
+            shop_prices: Default::default(),
             text: Default::default(),
         }
     }
 }
 
+/// A Multiworld seed: the worlds of every player, sharing one spoiler log.
+#[derive(Serialize, Debug)]
+pub struct MultiworldSeedInfo {
+    pub seed: u32,
+
+    pub version: String,
+
+    pub hash: SeedHash,
+
+    /// Each player's world, in player order.
+    #[serde(rename = "players", serialize_with = "serialize_worlds")]
+    pub worlds: Vec<SeedInfo>,
+}
+
+fn serialize_worlds<S>(worlds: &[SeedInfo], ser: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    ser.collect_map(worlds.iter().map(|seed_info| (seed_info.player, seed_info)))
+}
+
 /// Main entry point to generate one ALBWR Seed.
 pub fn generate_seed(
     seed: u32, settings: Settings, user_config: &UserConfig, no_patch: bool, no_spoiler: bool,
@@ -390,8 +510,14 @@ pub fn generate_seed(
 
     // settings.log_settings();
 
-    let seed_info = &calculate_seed_info(seed, settings, hash, rng)?;
-    patch_seed(seed_info, user_config, no_patch, no_spoiler)?;
+    println!();
+    if settings.players > 1 {
+        let multiworld = &calculate_multiworld_seed_info(seed, settings, hash, rng)?;
+        patch_multiworld_seed(multiworld, user_config, no_patch, no_spoiler)?;
+    } else {
+        let seed_info = &calculate_seed_info(seed, settings, hash, rng)?;
+        patch_seed(seed_info, user_config, no_patch, no_spoiler)?;
+    }
 
     Ok(())
 }
@@ -400,7 +526,7 @@ pub fn generate_seed(
 ///
 /// The hash is calculated as `u64`, truncated to `u16` (5 digits), then converted to a Symbolic form that can be
 /// displayed in-game as well as in the spoiler log.
-#[derive(Default, Debug)]
+#[derive(Clone, Default, Debug)]
 pub struct SeedHash {
     item_hash: String,
     text_hash: String,
@@ -471,6 +597,135 @@ fn validate_settings(settings: &Settings) -> Result<()> {
         );
     }
 
+    // Pedestal Requirement
+    if let PedestalSetting::Count { pendants, portraits } = settings.ped_requirement {
+        if pendants > PedestalSetting::MAX_PENDANTS || portraits > PedestalSetting::MAX_PORTRAITS {
+            fail!(
+                "Invalid Pedestal Requirement: \"{} Pendants and {} Portraits\" exceeds the {} Pendants and {} Portraits available.",
+                pendants,
+                portraits,
+                PedestalSetting::MAX_PENDANTS,
+                PedestalSetting::MAX_PORTRAITS
+            );
+        }
+    }
+
+    // Partial Maiamai Madness
+    if let MaiamaiShuffle::Random(amount) = settings.maiamai_shuffle {
+        if amount > 100 {
+            fail!("Invalid Maiamai Shuffle: \"{}\" was not between 0-100, inclusive.", amount);
+        }
+    }
+
+    // Sage Portraits have no in-game GetItem outside their dungeon events, and those events can't hand out other items
+    if settings.prizes_anywhere {
+        fail!(
+            "Invalid Dungeon Prizes Anywhere: Pendants and Portraits can't be patched outside their prize checks yet."
+        );
+    }
+
+    // A Key picked up outside its dungeon counts for whichever dungeon Link is in, and has no text naming its owner
+    if settings.small_keysanity != Keysanity::OwnDungeon || settings.big_keysanity != Keysanity::OwnDungeon {
+        fail!(
+            "Invalid Keysanity: Keys can't be patched outside their own dungeon yet, only \"OwnDungeon\" is supported."
+        );
+    }
+
+    // The same goes for a Compass, which also marks its dungeon's map only while it's counted for that dungeon
+    if settings.compass_shuffle == CompassShuffle::Anywhere {
+        fail!(
+            "Invalid Compass Shuffle: Compasses can't be patched outside their own dungeon yet, only \"Vanilla\" and \
+            \"OwnDungeon\" are supported."
+        );
+    }
+
+    // Decoupled Weather Vanes only change how the shuffled vanes are mapped
+    if settings.decouple_weather_vanes && settings.weather_vanes != WeatherVanes::Shuffled {
+        fail!("Invalid Decoupled Weather Vanes: Weather Vanes must be \"Shuffled\" to be decoupled.");
+    }
+
+    // Starting Hearts
+    if !(1..=20).contains(&settings.starting_hearts) {
+        fail!("Invalid Starting Hearts: \"{}\" was not between 1-20, inclusive.", settings.starting_hearts);
+    }
+
+    // The new-game heart total isn't a known save field yet, so the game would always start with 3 Hearts
+    if settings.starting_hearts != 3 {
+        fail!(
+            "Invalid Starting Hearts: \"{}\" can't be patched yet, only 3 Starting Hearts are supported.",
+            settings.starting_hearts
+        );
+    }
+
+    // Heart Cap
+    if !(settings.starting_hearts..=20).contains(&settings.heart_cap) {
+        fail!(
+            "Invalid Heart Cap: \"{}\" was not between the Starting Hearts ({}) and 20, inclusive.",
+            settings.heart_cap,
+            settings.starting_hearts
+        );
+    }
+
+    // Shop Prices
+    if settings.shop_prices == ShopPrices::Random {
+        if settings.shop_price_max > MAX_SHOP_PRICE {
+            fail!(
+                "Invalid Shop Price Maximum: \"{}\" was not between 0-{}, inclusive.",
+                settings.shop_price_max,
+                MAX_SHOP_PRICE
+            );
+        }
+
+        if settings.shop_price_min > settings.shop_price_max {
+            fail!(
+                "Invalid Shop Price Minimum: \"{}\" was greater than the Shop Price Maximum ({}).",
+                settings.shop_price_min,
+                settings.shop_price_max
+            );
+        }
+    }
+
+    // Traps
+    if settings.trap_frequency > 100 {
+        fail!("Invalid Trap Frequency: \"{}\" was not between 0-100, inclusive.", settings.trap_frequency);
+    }
+
+    if settings.trap_frequency > 0 && settings.trap_types.is_empty() {
+        fail!("Invalid Trap Types: at least one trap must be enabled when the Trap Frequency is above 0.");
+    }
+
+    // Treacherous Tower
+    treacherous_tower::validate_floors(settings)?;
+
+    // Progression Balancing
+    if settings.fill_algorithm == FillAlgorithm::ProgressionBalancing && settings.sphere_depth == 0 {
+        fail!("Invalid Sphere Depth: Progression Balancing needs a target of at least 1 sphere.");
+    }
+
+    // Multiworld
+    if !(1..=MAX_PLAYERS).contains(&settings.players) {
+        fail!("Invalid Players: \"{}\" was not between 1-{}, inclusive.", settings.players, MAX_PLAYERS);
+    }
+
+    if settings.players > 1 {
+        if settings.accessibility != Accessibility::AllLocations {
+            fail!("Multiworld seeds require the AllLocations accessibility setting.");
+        }
+
+        if settings.fill_algorithm != FillAlgorithm::AssumedFill {
+            fail!("Multiworld seeds require the AssumedFill fill_algorithm setting.");
+        }
+    }
+
+    // Boss Hunt
+    if settings.boss_hunt != BossHunt::Off && !(1..=BOSS_HUNT_BOSSES).contains(&settings.boss_hunt_count) {
+        fail!(
+            "Invalid Boss Hunt Count: \"{}\" was not between 1-{}, inclusive.",
+            settings.boss_hunt_count,
+            BOSS_HUNT_BOSSES
+        );
+    }
+
     // Progressive Bow of Light
     if settings.progressive_bow_of_light && settings.bow_of_light_in_castle {
         fail!("The progressive_bow_of_light and bow_of_light_in_castle settings cannot both be enabled.");
@@ -502,50 +757,116 @@ pub type DashSet<T> = HashSet<T, BuildHasherDefault<XxHash64>>;
 /// Map of all checks (as Strings) to their held item
 pub type CheckMap = DashMap<String, Option<Randomizable>>;
 
-/// Map of all cracks to their destination cracks. Map is not bidirectional to allow for (eventual) decoupled shuffle,
-/// so each Crack and its destination must have a corresponding reversed entry.
+/// Map of all cracks to their destination cracks. Map is not bidirectional to allow for Decoupled Cracksanity, so for the
+/// paired modes each Crack and its destination must have a corresponding reversed entry.
 pub type CrackMap = BTreeMap<Crack, Crack>;
 
 /// Map of all Weather Vanes to the destination Vanes they unlock.
 pub type VaneMap = BTreeMap<Vane, Vane>;
 
-fn calculate_seed_info(seed: u32, settings: Settings, hash: SeedHash, rng: &mut StdRng) -> Result<SeedInfo> {
-    println!();
+/// Map of all overworld Dungeon Entrances to the dungeon found behind them.
+pub type DungeonEntranceMap = BTreeMap<DungeonEntrance, DungeonEntrance>;
+
+/// Map of each boss arena to the boss fought there.
+pub type BossMap = BTreeMap<BossArena, Boss>;
+
+/// Randomizes everything about a seed without touching the ROM: the world layout, item placement, metrics and hints.
+pub fn calculate_seed_info(seed: u32, settings: Settings, hash: SeedHash, rng: &mut StdRng) -> Result<SeedInfo> {
     info!("Calculating Seed Info...");
 
+    let mut seed_info = build_world(seed, settings, hash, PlayerId::default(), rng)?;
+
+    // Check Map and Item Pools
+    let check_map = &mut filler::prefill_check_map(&mut seed_info.world_graph);
+
+    // Filler Algorithm
+    filler::fill_all_locations_reachable(rng, &mut seed_info, check_map)?;
+
+    // Post-analysis: Metrics and Hints
+    metrics::calculate_metrics(&mut seed_info, check_map)?;
+    hints::generate_hints(rng, &mut seed_info, check_map)?;
+
+    Ok(seed_info)
+}
+
+/// Randomizes a Multiworld seed: one world per player, filled together so that a player's items may be found in
+/// another player's world. Each world's hints point to the checks of that world only.
+pub fn calculate_multiworld_seed_info(
+    seed: u32, settings: Settings, hash: SeedHash, rng: &mut StdRng,
+) -> Result<MultiworldSeedInfo> {
+    info!("Calculating Multiworld Seed Info...");
+
+    let mut worlds = Vec::with_capacity(settings.players as usize);
+    for index in 0..settings.players {
+        worlds.push(build_world(seed, settings.clone(), hash.clone(), PlayerId::new(index), rng)?);
+    }
+
+    let mut check_maps =
+        worlds.iter_mut().map(|seed_info| filler::prefill_check_map(&mut seed_info.world_graph)).collect::<Vec<_>>();
+
+    filler::multiworld::fill_multiworld(rng, &mut worlds, &mut check_maps)?;
+    metrics::calculate_multiworld_metrics(&mut worlds, &check_maps)?;
+
+    // Hints are generated per world, assuming every item found for its player in the other worlds
+    for check_map in &check_maps {
+        for item in check_map.values().flatten() {
+            if let Randomizable::Remote(player, item) = item {
+                worlds[player.index()].received_items.push(*item);
+            }
+        }
+    }
+    for (seed_info, check_map) in worlds.iter_mut().zip(&mut check_maps) {
+        hints::generate_hints(rng, seed_info, check_map)?;
+    }
+
+    Ok(MultiworldSeedInfo { seed, version: VERSION.to_owned(), hash, worlds })
+}
+
+/// Randomizes the layout of one player's world: everything decided before items are placed.
+fn build_world(seed: u32, settings: Settings, hash: SeedHash, player: PlayerId, rng: &mut StdRng) -> Result<SeedInfo> {
     let crack_map = cracks::build_crack_map(&settings, rng)?;
     let vane_map = vanes::build_vanes_map(&settings, rng)?;
+    let dungeon_entrance_map = dungeon_entrances::build_dungeon_entrance_map(&settings, rng)?;
+    let boss_map = bosses::build_boss_map(&settings, rng)?;
     let text = text::generate(rng)?;
     let trials_config = trials::configure(rng, &settings)?;
     let treacherous_tower_floors = treacherous_tower::choose_floors(&settings, rng)?;
-    let world_graph = world::build_world_graph(&crack_map);
+    let required_bosses = boss_hunt::choose_bosses(&settings, rng);
+    info!("Building World Graph...");
+    let world_graph = world::build_world_graph(&crack_map, &dungeon_entrance_map, &Default::default());
 
     let mut seed_info = SeedInfo {
         seed,
         version: VERSION.to_owned(),
         hash,
         settings,
+        player,
+        received_items: Default::default(),
         full_exclusions: Default::default(),
+        start: Default::default(),
         vane_map,
         crack_map,
+        dungeon_entrance_map,
+        interior_map: Default::default(),
+        boss_map,
         layout: Default::default(),
+        unreachable_checks: Default::default(),
         metrics: Default::default(),
         hints: Default::default(),
         trials_config,
         world_graph,
         text,
         treacherous_tower_floors,
+        required_bosses,
+        shop_prices: Default::default(),
     };
 
-    // Check Map and Item Pools
-    let check_map = &mut filler::prefill_check_map(&mut seed_info.world_graph);
-
-    // Filler Algorithm
-    filler::fill_all_locations_reachable(rng, &mut seed_info, check_map)?;
+    seed_info.start = start::choose_start(rng, &seed_info);
+    plando::connect_crack_pins(rng, &mut seed_info)?;
 
-    // Post-analysis: Metrics and Hints
-    metrics::calculate_metrics(&mut seed_info, check_map)?;
-    hints::generate_hints(rng, &mut seed_info, check_map)?;
+    // Interior Shuffle is checked against the filler's accessibility check, so is done once the rest is in place
+    interiors::shuffle_interiors(rng, &mut seed_info)?;
+    seed_info.shop_prices = shop_prices::choose_prices(&seed_info.settings, rng);
 
     Ok(seed_info)
 }
@@ -554,45 +875,93 @@ pub fn patch_seed(seed_info: &SeedInfo, user_config: &UserConfig, no_patch: bool
     println!();
 
     if !no_patch {
-        info!("Starting Patch Process...");
-
-        let game = match Rom::load(user_config.rom()) {
-            Ok(rom) => rom,
-            Err(_) => {
-                // Retry once, people keep naming their ROMs "ALBW.3ds.3ds" :P
-                Rom::load(format!("{}.3ds", user_config.rom().to_str().unwrap()))?
-            },
-        };
-        let mut patcher = Patcher::new(game)?;
+        patch_world(seed_info, user_config, user_config.output())?;
+    }
+    if !no_spoiler {
+        //let spoiler = Spoiler::from(seed_info);
 
-        info!("ROM Loaded.\n");
+        write_spoiler(seed_info.seed, seed_info, user_config)?;
+    }
+
+    // let path = user_config.output().join(format!("{:0>10}_world_graph.json", seed_info.seed));
+    // let world_graph = serde_json::to_string_pretty(&seed_info.world_graph).unwrap();
+    // write!(File::create(path)?, "{}", world_graph).expect("Could not write World Graph");
 
-        // patch::lms::msbf::research(&mut patcher, None, "HintGhost", vec![], true)?;
+    Ok(())
+}
 
-        // patch::research_msbf_msbt(&mut patcher,
-        //     game::Course::IndoorLight, "FieldLight_18_SahasPupil", // MSBF
-        //     game::Course::IndoorLight, "FieldLight_18", // MSBT
-        //     true);
+/// Patches each player's world into its own folder of the output directory, and writes their shared spoiler log.
+pub fn patch_multiworld_seed(
+    multiworld: &MultiworldSeedInfo, user_config: &UserConfig, no_patch: bool, no_spoiler: bool,
+) -> Result<()> {
+    println!();
 
-        regions::patch(&mut patcher, seed_info)?;
-        let patches = patcher.prepare(seed_info)?;
-        patches.dump(user_config.output())?;
+    if !no_patch {
+        for seed_info in &multiworld.worlds {
+            let output = user_config.output().join(seed_info.player.to_string());
+            fs::create_dir_all(&output)?;
+            patch_world(seed_info, user_config, &output)?;
+        }
     }
+
     if !no_spoiler {
-        let path = user_config.output().join(format!("{:0>10}_spoiler.json", seed_info.seed));
-        info!("Writing Spoiler Log to:         {}", &path.absolutize()?.display());
+        write_spoiler(multiworld.seed, multiworld, user_config)?;
+    }
 
-        //let spoiler = Spoiler::from(seed_info);
+    Ok(())
+}
 
-        let mut serialized = serde_json::to_string_pretty(&seed_info).unwrap();
-        align_json_values(&mut serialized);
+/// Loads the ROM and writes the patch files of one player's world to `output`.
+fn patch_world(seed_info: &SeedInfo, user_config: &UserConfig, output: &Path) -> Result<()> {
+    if seed_info.settings.trap_frequency > 0 {
+        fail!(
+            "Traps can't be patched yet: they need code hooks to run when their fake get-item fires. Generate the \
+            seed with --no-patch to get a spoiler log."
+        );
+    }
 
-        write!(File::create(path)?, "{}", serialized).expect("Could not write the spoiler log.");
+    if seed_info.settings.boss_shuffle {
+        fail!(
+            "Boss Shuffle can't be patched yet: each boss's actors and resources still need mapping into the other \
+            arenas. Generate the seed with --no-patch to get a spoiler log."
+        );
     }
 
-    // let path = user_config.output().join(format!("{:0>10}_world_graph.json", seed_info.seed));
-    // let world_graph = serde_json::to_string_pretty(&seed_info.world_graph).unwrap();
-    // write!(File::create(path)?, "{}", world_graph).expect("Could not write World Graph");
+    info!("Starting Patch Process...");
+
+    let game = match Rom::load(user_config.rom()) {
+        Ok(rom) => rom,
+        Err(_) => {
+            // Retry once, people keep naming their ROMs "ALBW.3ds.3ds" :P
+            Rom::load(format!("{}.3ds", user_config.rom().to_str().unwrap()))?
+        },
+    };
+    let mut patcher = Patcher::new(game)?;
+
+    info!("ROM Loaded.\n");
+
+    // patch::lms::msbf::research(&mut patcher, None, "HintGhost", vec![], true)?;
+
+    // patch::research_msbf_msbt(&mut patcher,
+    //     game::Course::IndoorLight, "FieldLight_18_SahasPupil", // MSBF
+    //     game::Course::IndoorLight, "FieldLight_18", // MSBT
+    //     true);
+
+    regions::patch(&mut patcher, seed_info)?;
+    let patches = patcher.prepare(seed_info)?;
+    patches.dump(output)?;
+
+    Ok(())
+}
+
+fn write_spoiler(seed: u32, spoiler: &impl Serialize, user_config: &UserConfig) -> Result<()> {
+    let path = user_config.output().join(format!("{:0>10}_spoiler.json", seed));
+    info!("Writing Spoiler Log to:         {}", &path.absolutize()?.display());
+
+    let mut serialized = serde_json::to_string_pretty(spoiler).unwrap();
+    align_json_values(&mut serialized);
+
+    write!(File::create(path)?, "{}", serialized).expect("Could not write the spoiler log.");
 
     Ok(())
 }
@@ -1,6 +1,7 @@
 use crate::filler::check::Check;
 use crate::filler::cracks::Crack;
-use crate::filler::filler_item::{Goal, Item, Randomizable, Vane};
+use crate::filler::filler_item::{Goal, Randomizable, Vane};
+use crate::filler::item_set::ItemSet;
 use crate::filler::progress::Progress;
 use crate::{filler, CheckMap, SeedInfo};
 use game::ghosts::HintGhost;
@@ -20,9 +21,25 @@ pub fn calculate_metrics(seed_info: &mut SeedInfo, check_map: &mut CheckMap) ->
     Ok(())
 }
 
+/// Calculates the Metrics of every world of a Multiworld seed.
+///
+/// The playthrough is shared: each sphere holds what every player can find with the items found for them in the spheres
+/// before, whichever world those were found in. Each world lists the spheres found in it.
+pub fn calculate_multiworld_metrics(worlds: &mut [SeedInfo], check_maps: &[CheckMap]) -> Result<(), Error> {
+    info!("Calculating Metrics...");
+
+    let (spheres, playthroughs) = multiworld_sphere_search(worlds, check_maps);
+
+    for (seed_info, playthrough) in worlds.iter_mut().zip(playthroughs) {
+        seed_info.metrics = Metrics { spheres, playthrough };
+    }
+
+    Ok(())
+}
+
 #[derive(Debug, Clone, Serialize)]
 pub struct Sphere {
-    items: BTreeMap<String, Item>,
+    items: BTreeMap<String, Randomizable>,
 
     #[serde(skip_serializing_if = "BTreeMap::is_empty")]
     ghosts: BTreeMap<String, HintGhost>,
@@ -50,7 +67,7 @@ impl Sphere {
 
     fn add(&mut self, check_name: &str, item: Randomizable) {
         match item {
-            Randomizable::Item(item) => {
+            Randomizable::Item(_) | Randomizable::Remote(..) | Randomizable::Trap(_) => {
                 self.items.insert(String::from(check_name), item);
             },
             Randomizable::Goal(goal) => {
@@ -96,14 +113,12 @@ fn sphere_search(seed_info: &mut SeedInfo, check_map: &mut CheckMap) -> BTreeMap
             break;
         }
 
-        for new_item in &new_items {
-            progress.add_item(*new_item);
-        }
+        progress.add_items(&new_items);
 
         let mut sphere = Sphere::new();
         for reachable_check in reachable_checks {
             let filler_item = check_map.get(reachable_check.get_name()).unwrap().unwrap();
-            if new_items.contains(&filler_item) && filler_item.include_in_sphere_search() {
+            if filler_item.include_in_sphere_search() && new_items.contains(filler_item) {
                 sphere.add(reachable_check.get_name(), filler_item);
             }
         }
@@ -118,6 +133,73 @@ fn sphere_search(seed_info: &mut SeedInfo, check_map: &mut CheckMap) -> BTreeMap
     spheres
 }
 
+/// Sphere Search across every world of a Multiworld seed, returning the number of spheres and each world's playthrough.
+fn multiworld_sphere_search(worlds: &[SeedInfo], check_maps: &[CheckMap]) -> (usize, Vec<Playthrough>) {
+    info!("Generating Playthrough...");
+
+    let mut progresses = worlds.iter().map(Progress::new).collect::<Vec<_>>();
+    let mut playthroughs = vec![Playthrough::new(); worlds.len()];
+    let mut sphere_num = 0;
+
+    loop {
+        let reachable_checks = worlds
+            .iter()
+            .zip(&progresses)
+            .map(|(seed_info, progress)| filler::find_reachable_checks(seed_info, progress))
+            .collect::<Vec<_>>();
+
+        // Items found for other players are handed over to them
+        let mut reachable_items = worlds.iter().map(Progress::new).collect::<Vec<_>>();
+        for (world, checks) in reachable_checks.iter().enumerate() {
+            for check in checks {
+                for item in filler::get_check_items(&worlds[world], check, &check_maps[world]) {
+                    match item {
+                        Randomizable::Remote(player, item) => reachable_items[player.index()].add_item(item),
+                        item => reachable_items[world].add_item(item),
+                    }
+                }
+            }
+        }
+
+        let new_items = reachable_items.iter().zip(&progresses).map(|(items, progress)| items.difference(progress));
+        let new_items = new_items.collect::<Vec<_>>();
+
+        if new_items.iter().all(ItemSet::is_empty) {
+            break;
+        }
+
+        for (progress, new_items) in progresses.iter_mut().zip(&new_items) {
+            progress.add_items(new_items);
+        }
+
+        let mut sphere_found = false;
+        for (world, checks) in reachable_checks.into_iter().enumerate() {
+            let mut sphere = Sphere::new();
+            for reachable_check in checks {
+                let filler_item = check_maps[world].get(reachable_check.get_name()).unwrap().unwrap();
+                let (player, item) = match filler_item {
+                    Randomizable::Remote(player, item) => (player.index(), item.into()),
+                    item => (world, item),
+                };
+                if item.include_in_sphere_search() && new_items[player].contains(item) {
+                    sphere.add(reachable_check.get_name(), filler_item);
+                }
+            }
+            if !sphere.is_empty() {
+                playthroughs[world].insert(format!("Sphere {:02}", sphere_num), sphere);
+                sphere_found = true;
+            }
+        }
+
+        // Spheres with only minor progression items are hidden
+        if sphere_found {
+            sphere_num += 1;
+        }
+    }
+
+    (sphere_num, playthroughs)
+}
+
 #[derive(Default, Debug, Clone, Serialize)]
 pub struct Metrics {
     spheres: usize,
@@ -59,6 +59,34 @@ pub fn patch(patcher: &mut Patcher, prizes: &DungeonPrizes, seed_info: &SeedInfo
     patch_desert_maps(patcher, seed_info);
     patch_ice_maps(patcher, seed_info);
     patch_lorule_castle_maps(patcher, seed_info);
+    patch_informational_compasses(patcher, seed_info);
+}
+
+/// Informational Compasses: hide the map icons of chests that don't hold a major item, and only show the rest once the
+/// dungeon's Compass chest is opened, so that the Compass reveals how many major items are left in its chests.
+fn patch_informational_compasses(patcher: &mut Patcher, seed_info: &SeedInfo) {
+    if !seed_info.settings.informational_compasses {
+        return;
+    }
+
+    const DUNGEON_MAPS: [Course; 12] = [
+        DungeonEast, DungeonWind, DungeonHera, DungeonCastle, DungeonDark, DungeonWater, DungeonDokuro, DungeonHagure,
+        DungeonKame, DungeonSand, DungeonIce, DungeonGanon,
+    ];
+
+    for map in DUNGEON_MAPS {
+        let minor_chests = patcher.minor_chests.get(&map).cloned().unwrap_or_default();
+        let compass_flag = patcher.compass_chests.get(&map).copied();
+
+        let stage_meta = patcher.scene_meta(map).stage_meta_mut().get_mut();
+        for icn in stage_meta.icn.iter_mut().filter(|icn| icn.arg.0 == Icon::TREASURE_BOX) {
+            if minor_chests.contains(&(icn.arg.5, icn.arg.7)) {
+                icn.disable();
+            } else if let Some(compass_flag) = compass_flag {
+                icn.enable_on(compass_flag);
+            }
+        }
+    }
 }
 
 /// Hyrule Field Maps
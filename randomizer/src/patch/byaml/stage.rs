@@ -1,9 +1,11 @@
 use crate::filler::cracks::Crack;
+use crate::filler::interiors::get_interior_zones;
 use crate::patch::Patcher;
 use crate::{patch::util::*, regions, Result, SeedInfo};
 use game::Course::{self, *};
 use log::info;
 use macros::fail;
+use modinfo::settings::boss_hunt::BossHunt;
 use modinfo::settings::cracksanity::Cracksanity;
 use modinfo::settings::keysy::Keysy;
 use modinfo::settings::{Settings, TrialsDoor};
@@ -97,6 +99,7 @@ pub fn patch(patcher: &mut Patcher, seed_info: &SeedInfo) -> Result<()> {
 
     patch_flag_510_effects(patcher)?;
     patch_ravios_shop(patcher)?;
+    patch_ravios_shop_prices(patcher)?;
     patch_sahasrahlas_house(patcher)?;
     patch_maiamai_cave(patcher);
     patch_treacherous_tower(patcher, seed_info)?;
@@ -119,9 +122,11 @@ pub fn patch(patcher: &mut Patcher, seed_info: &SeedInfo) -> Result<()> {
     patch_blacksmith_lorule(patcher);
     patch_trials_door(patcher, &seed_info.settings);
     patch_hildas_study(patcher, &seed_info.settings);
+    patch_boss_hunt(patcher, &seed_info.settings);
 
     patch_curtain(patcher, seed_info);
     patch_cracksanity(patcher);
+    patch_interiors(patcher, seed_info);
     patch_keysy_small(patcher, &seed_info.settings);
     patch_keysy_big(patcher, &seed_info.settings);
     // patch_reverse_desert_palace(patcher, settings);
@@ -821,6 +826,32 @@ fn patch_ravios_shop(patcher: &mut Patcher) -> Result<()> {
     Ok(())
 }
 
+/// Shop Prices - Every item stand shares the Tornado Rod stand's actor, and sells from the shop slot in its first argument
+fn patch_ravios_shop_prices(patcher: &mut Patcher) -> Result<()> {
+    const KNOWN_STANDS: [(u16, i32); 3] = [(15, 2), (17, 8), (19, 7)]; // Tornado Rod, Bow, Hammer
+
+    if patcher.rental_prices.iter().all(Option::is_none) {
+        return Ok(());
+    }
+
+    let stand_id = patcher.read_obj(IndoorLight, 1, KNOWN_STANDS[0].0).id;
+    let stands = patcher.scene(IndoorLight, 0)?.stage().get().find_objs_by_id(stand_id);
+
+    let mut slots = stands.iter().map(|&(_, slot)| slot).collect::<Vec<_>>();
+    slots.sort_unstable();
+    if slots != (0..9).collect::<Vec<_>>() || !KNOWN_STANDS.iter().all(|stand| stands.contains(stand)) {
+        fail!("Could not match Ravio's item stands to their shop slots: {:?}", stands);
+    }
+
+    for (unq, slot) in stands {
+        if let Some(price) = patcher.rental_prices[slot as usize] {
+            patcher.modify_objs(IndoorLight, 1, [call(unq, move |obj| obj.arg.3 = price as i32)]);
+        }
+    }
+
+    Ok(())
+}
+
 fn patch_treacherous_tower(patcher: &mut Patcher, seed_info: &SeedInfo) -> Result<()> {
     let tower_floors = &seed_info.treacherous_tower_floors;
 
@@ -840,14 +871,16 @@ fn patch_treacherous_tower(patcher: &mut Patcher, seed_info: &SeedInfo) -> Resul
     }
 
     // Final Floor (Moldorm)
-    patcher.modify_objs(
-        EnemyAttackS,
-        5,
-        [
-            call(23, |obj| obj.arg.0 = 1), // Change reward to 1000 rupees
-                                           // call(23, |obj| obj.arg.0 = 2), // Change reward to 5000 rupees
-        ],
-    );
+    if !seed_info.settings.treacherous_tower_vanilla_reward {
+        patcher.modify_objs(
+            EnemyAttackS,
+            5,
+            [
+                call(23, |obj| obj.arg.0 = 1), // Change reward to 1000 rupees
+                                               // call(23, |obj| obj.arg.0 = 2), // Change reward to 5000 rupees
+            ],
+        );
+    }
 
     Ok(())
 }
@@ -1365,6 +1398,29 @@ fn patch_curtain(patcher: &mut Patcher, seed_info: &SeedInfo) {
     }
 }
 
+/// Redirects each shuffled door and interior exit to the spawn point of its new destination.
+fn patch_interiors(patcher: &mut Patcher, seed_info: &SeedInfo) {
+    let (doors, exits) = get_interior_zones();
+
+    for (door_id, interior) in seed_info.interior_map.shuffled_doors() {
+        let (door, interior) = (&doors[&door_id], &exits[&interior]);
+        let spawn_point =
+            SpawnPoint::new(interior.get_scene(), interior.get_scene_id() as i32, interior.get_spawn() as i32);
+        let unqs = std::iter::once(door.get_unq()).chain(door_id.get_duplicate_doors().iter().copied());
+        patcher.modify_objs(
+            door.get_scene(),
+            door.get_scene_id(),
+            unqs.map(|unq| redirect(unq, spawn_point)).collect::<Vec<_>>(),
+        );
+    }
+
+    for (interior, door) in seed_info.interior_map.shuffled_exits() {
+        let (interior, door) = (&exits[&interior], &doors[&door]);
+        let spawn_point = SpawnPoint::new(door.get_scene(), door.get_scene_id() as i32, door.get_spawn() as i32);
+        patcher.modify_objs(interior.get_scene(), interior.get_scene_id(), [redirect(interior.get_unq(), spawn_point)]);
+    }
+}
+
 fn patch_cracksanity(patcher: &mut Patcher) {
     // Eastern Ruins SE Crack Blockage
     patcher.modify_objs(
@@ -1495,6 +1551,27 @@ fn patch_hildas_study(patcher: &mut Patcher, settings: &Settings) {
     );
 }
 
+/// Boss Hunt (End Game): a warp to the Sacred Realm appears in Ravio's Shop once the hunt sets the credits flag
+fn patch_boss_hunt(patcher: &mut Patcher, settings: &Settings) {
+    if settings.boss_hunt != BossHunt::EndGame {
+        return;
+    }
+
+    let (unq, ser) = patcher.find_objs_unq_ser(IndoorLight, 1);
+    patcher.add_obj(
+        IndoorLight,
+        1,
+        Obj::green_warp(
+            Flag::CREDITS,
+            0,
+            ser,
+            unq,
+            SpawnPoint::new(FieldLight, 43, 0),
+            Vec3 { x: 3.0, y: 0.0, z: -3.5 },
+        ),
+    );
+}
+
 /// Reverse Desert Palace
 ///
 /// Make Desert Palace make sense as a dungeon for glitchless players who enter from 3F.
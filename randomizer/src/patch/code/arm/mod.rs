@@ -328,12 +328,15 @@ where
     Instruction::Branch { cond: Default::default(), target_address: target_address.into(), link: true }
 }
 
-pub fn assemble<A, const N: usize>(start: A, instructions: [Instruction; N]) -> Box<[u8]>
+pub fn assemble<A, I>(start: A, instructions: I) -> Box<[u8]>
 where
     A: Into<Address>,
+    I: IntoIterator<Item = Instruction>,
+    I::IntoIter: ExactSizeIterator,
 {
-    let mut assembler = Assembler::new(start.into(), N * 4);
-    for instruction in IntoIterator::into_iter(instructions) {
+    let instructions = instructions.into_iter();
+    let mut assembler = Assembler::new(start.into(), instructions.len() * 4);
+    for instruction in instructions {
         let code = instruction.assemble(&mut assembler);
         assembler.write(code);
     }
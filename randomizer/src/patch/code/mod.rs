@@ -6,10 +6,14 @@ use crate::patch::code::arm::data::{add, cmp, mov};
 use crate::patch::code::arm::ls::{ldr, ldrb, str_, strb};
 use crate::patch::code::arm::lsm::{pop, push};
 use crate::patch::code::arm::{Instruction, LR, PC, SP, b, bl};
-use crate::{Layout, Result, SeedInfo, patch::util::prize_flag, regions};
+use crate::{
+    Result, SeedInfo,
+    patch::util::{boss_flag, get_dungeon_prizes, prize_flag, remote_items},
+    regions,
+};
 use game::Item;
 use game::Item::*;
-use modinfo::settings::{Settings, pedestal::PedestalSetting::*};
+use modinfo::settings::{BossHunt, MaiamaiRewards, Settings, pedestal::PedestalSetting::*};
 use rom::ExHeader;
 use rom::flag::Flag;
 use rom::scene::SpawnPoint;
@@ -100,14 +104,22 @@ impl<'a> Segment<'a> {
         addr
     }
 
-    pub fn define<const N: usize>(&mut self, instructions: [Instruction; N]) -> u32 {
+    pub fn define<I>(&mut self, instructions: I) -> u32
+    where
+        I: IntoIterator<Item = Instruction>,
+        I::IntoIter: ExactSizeIterator,
+    {
         let addr = *self.address;
         let len = self.patch(addr, instructions);
         *self.address += len;
         addr
     }
 
-    pub fn patch<const N: usize>(&mut self, addr: u32, instructions: [Instruction; N]) -> u32 {
+    pub fn patch<I>(&mut self, addr: u32, instructions: I) -> u32
+    where
+        I: IntoIterator<Item = Instruction>,
+        I::IntoIter: ExactSizeIterator,
+    {
         let code = arm::assemble(addr, instructions);
         let len = code.len() as u32;
         self.write(addr, code);
@@ -166,6 +178,7 @@ pub fn create(patcher: &Patcher, seed_info: &SeedInfo) -> Code {
     // shield_without_sword(&mut code);
     // swordless_beams(&mut code);
     quake(&mut code);
+    multiworld_items(&mut code, seed_info);
 
     // Start with Pouch
     if seed_info.settings.start_with_pouch {
@@ -186,9 +199,10 @@ pub fn create(patcher: &Patcher, seed_info: &SeedInfo) -> Code {
     ore_progress(&mut code);
     merchant(&mut code);
     configure_pedestal_requirements(&mut code, &seed_info.settings);
+    boss_hunt(&mut code, seed_info);
     night_mode(&mut code, &seed_info.settings);
     show_hint_ghosts(&mut code);
-    mother_maiamai(&mut code, &seed_info.layout, &item_names);
+    mother_maiamai(&mut code, seed_info, &item_names);
     pause_menu_warp(&mut code);
     purple_potion_bottles(&mut code, &seed_info.settings);
     // golden_bees(&mut code);
@@ -438,8 +452,17 @@ fn quake(code: &mut Code) {
     code.addr(0x344848, earthquake); // Empty -> Quake
 }
 
+/// Multiworld: the placeholder get-items standing in for other players' items give nothing to this player
+fn multiworld_items(code: &mut Code, seed_info: &SeedInfo) {
+    for (_, (item, _, _)) in remote_items(seed_info) {
+        code.addr(0x344848 + 4 * item as u32, 0x344F00);
+    }
+}
+
 /// Mother Maiamai Stuff
-fn mother_maiamai(code: &mut Code, layout: &Layout, item_names: &HashMap<Item, u32>) {
+fn mother_maiamai(
+    code: &mut Code, SeedInfo { layout, settings, player, .. }: &SeedInfo, item_names: &HashMap<Item, u32>,
+) {
     /// Use flags 302-311 (not 305) to record whether we've picked up that item's upgrade.
     /// The "inventory index" (see table: 0x6a6170) of each item gets added to this:
     /// * 0x4 = Bow
@@ -514,16 +537,57 @@ fn mother_maiamai(code: &mut Code, layout: &Layout, item_names: &HashMap<Item, u
     ////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
     // Check our newly created local flags to determine if items can appear on MM's list of items to upgrade
-    let fn_get_maiamai_flag3 = code.text().define([
-        add(R1, R1, NEW_LOCAL_FLAGS_START_IDX),
-        ldr(R0, MAP_MANAGER_INSTANCE),
-        ldr(R0, (R0, 0x0)),
-        ldr(R0, (R0, 0x40)),
-        bl(FN_GET_LOCAL_FLAG_3),
-        cmp(R0, 0x0),
-        b(0x46d848).eq(),
-        b(0x46d888),
-    ]);
+    let fn_get_maiamai_flag3 = match settings.maiamai_rewards {
+        MaiamaiRewards::Upgrades => code.text().define([
+            add(R1, R1, NEW_LOCAL_FLAGS_START_IDX),
+            ldr(R0, MAP_MANAGER_INSTANCE),
+            ldr(R0, (R0, 0x0)),
+            ldr(R0, (R0, 0x40)),
+            bl(FN_GET_LOCAL_FLAG_3),
+            cmp(R0, 0x0),
+            b(0x46d848).eq(),
+            b(0x46d888),
+        ]),
+        MaiamaiRewards::Milestones => {
+            // Milestones: only list an item once the item before it (in Bow -> Sand Rod order) has been redeemed.
+            // Table maps each inventory index to the inventory index of the previous Milestone (0 = first).
+            let mut previous_milestone = [0u8; 0xC];
+            let milestone_order = [0x4, 0x3, 0xB, 0x6, 0x2, 0x8, 0x9, 0xA, 0x7];
+            for pair in milestone_order.windows(2) {
+                previous_milestone[pair[1] as usize] = pair[0];
+            }
+            let previous_milestone_table = code.rodata().declare(previous_milestone);
+
+            code.text().define([
+                push([R4, R5]),
+                ldr(R4, previous_milestone_table),
+                ldrb(R4, (R4, R1)),
+                // Skip if this reward has already been redeemed
+                add(R1, R1, NEW_LOCAL_FLAGS_START_IDX),
+                ldr(R0, MAP_MANAGER_INSTANCE),
+                ldr(R0, (R0, 0x0)),
+                ldr(R0, (R0, 0x40)),
+                bl(FN_GET_LOCAL_FLAG_3),
+                cmp(R0, 0x0),
+                pop([R4, R5]).ne(),
+                b(0x46d888).ne(),
+                // Always list the first Milestone
+                cmp(R4, 0x0),
+                pop([R4, R5]).eq(),
+                b(0x46d848).eq(),
+                // Otherwise list only if the previous Milestone has been redeemed
+                add(R1, R4, NEW_LOCAL_FLAGS_START_IDX),
+                ldr(R0, MAP_MANAGER_INSTANCE),
+                ldr(R0, (R0, 0x0)),
+                ldr(R0, (R0, 0x40)),
+                bl(FN_GET_LOCAL_FLAG_3),
+                cmp(R0, 0x0),
+                pop([R4, R5]),
+                b(0x46d888).eq(),
+                b(0x46d848),
+            ])
+        },
+    };
     code.patch(0x46d840, [b(fn_get_maiamai_flag3).ge()]);
     code.patch(0x46d844, [b(0x46d888)]);
 
@@ -577,7 +641,7 @@ fn mother_maiamai(code: &mut Code, layout: &Layout, item_names: &HashMap<Item, u
             ldr(R1, offset),
             mov(R2, 0x1),
             bl(FN_SET_LOCAL_FLAG_3),
-            mov(R0, item.as_item_index()),
+            mov(R0, item.as_item_index(*player)),
             b(0x310134),
         ]);
         code.patch(addr, [b(fn_set_local3_flag_for_this_upgrade)]);
@@ -591,9 +655,7 @@ fn mother_maiamai(code: &mut Code, layout: &Layout, item_names: &HashMap<Item, u
             .iter()
             .flat_map(|item| {
                 u32::to_le_bytes(
-                    *item_names
-                        .get(&Randomizable::normalize(*item))
-                        .unwrap_or_else(|| panic!("No item_name for: {item:?}")),
+                    *item_names.get(&item.normalize(*player)).unwrap_or_else(|| panic!("No item_name for: {item:?}")),
                 )
             })
             .collect::<Vec<_>>(),
@@ -749,10 +811,90 @@ fn configure_pedestal_requirements(code: &mut Code, settings: &Settings) {
                 b(RETURN_LABEL),
             ])
         },
+        Count { pendants, portraits } => {
+            // Tally the Pendants in R4 and the Portraits in R5, then compare against the requirement
+            let pendant_flags = [PendantOfPower, PendantOfWisdom, PendantOfCourage].map(|p| (p, R4));
+            let portrait_flags = {
+                use crate::filler::filler_item::Item::*;
+                [SageGulley, SageOren, SageSeres, SageOsfala, SageImpa, SageIrene, SageRosso].map(|p| (p, R5))
+            };
+            let tally = pendant_flags.into_iter().chain(portrait_flags).flat_map(|(prize, counter)| {
+                [
+                    ldr(R0, EVENT_FLAG_PTR),
+                    ldr(R0, (R0, 0x0)),
+                    ldr(R1, prize_flag(prize.into()).get_value() as u32),
+                    bl(FN_GET_EVENT_FLAG),
+                    cmp(R0, 0x0),
+                    add(counter, counter, 0x1).ne(),
+                ]
+            });
+
+            let instructions = [push([R4, R5]), mov(R4, 0x0), mov(R5, 0x0)]
+                .into_iter()
+                .chain(tally)
+                .chain([
+                    // Compare (pop doesn't touch the condition flags)
+                    cmp(R4, pendants as u32),
+                    pop([R4, R5]).lt(),
+                    b(RETURN_LABEL).lt(),
+                    cmp(R5, portraits as u32),
+                    pop([R4, R5]),
+                    b(RETURN_LABEL).lt(),
+                    // Set Flag
+                    ldr(R0, EVENT_FLAG_PTR),
+                    mov(R2, 0x1),
+                    ldr(R1, FLAG_PEDESTAL),
+                    ldr(R0, (R0, 0x0)),
+                    bl(FN_SET_EVENT_FLAG),
+                    b(RETURN_LABEL),
+                ])
+                .collect::<Vec<_>>();
+            code.text().define(instructions)
+        },
     };
     code.patch(0x143968, [b(ped_instructions)]);
 }
 
+/// Boss Hunt: after every item get, set the flag that completes the hunt once all required bosses are defeated
+fn boss_hunt(code: &mut Code, seed_info: &SeedInfo) {
+    let hunt_flag = match seed_info.settings.boss_hunt {
+        BossHunt::Off => return,
+        BossHunt::LoruleCastle => Flag::TRIFORCE_OF_COURAGE,
+        BossHunt::EndGame => Flag::CREDITS,
+    };
+
+    // Every item get case ends at 0x344F00, which returns 1 the same way the Tempered Sword barrier hook does
+    let item_get_return = code.text().define([mov(R0, 0x1), pop([R4, R5, R6, PC])]);
+
+    // Every required boss has a prize flag, as checked in `Patcher::prepare`
+    let prizes = get_dungeon_prizes(&seed_info.layout);
+    let flags = seed_info.required_bosses.iter().filter_map(|&boss| boss_flag(boss, &prizes));
+    let checks = flags.flat_map(|flag| {
+        [
+            ldr(R0, EVENT_FLAG_PTR),
+            ldr(R0, (R0, 0x0)),
+            ldr(R1, flag.get_value() as u32),
+            bl(FN_GET_EVENT_FLAG),
+            cmp(R0, 0x0),
+            b(item_get_return).eq(),
+        ]
+    });
+
+    let instructions = checks
+        .chain([
+            // Set Flag
+            ldr(R0, EVENT_FLAG_PTR),
+            mov(R2, 0x1),
+            ldr(R1, hunt_flag.get_value() as u32),
+            ldr(R0, (R0, 0x0)),
+            bl(FN_SET_EVENT_FLAG),
+            b(item_get_return),
+        ])
+        .collect::<Vec<_>>();
+    let check_boss_hunt = code.text().define(instructions);
+    code.patch(0x344F00, [b(check_boss_hunt)]);
+}
+
 fn merchant(code: &mut Code) {
     let get_merchant_event_flag =
         code.text().define([ldr(R0, EVENT_FLAG_PTR), ldr(R0, (R0, 0)), ldr(R1, 0x143), b(FN_GET_EVENT_FLAG)]);
@@ -1120,7 +1262,7 @@ const ACTOR_NAME_OFFSETS: [(Item, u32); 32] = [
     (HeartPiece, 0x5D7B94),
 ];
 
-const ACTOR_NAMES: [(Item, &str); 45] = [
+const ACTOR_NAMES: [(Item, &str); 51] = [
     (KeyBoss, "KeyBoss"),
     (TriforceCourage, "BadgeBee"),
     (Compass, "Compass"),
@@ -1166,6 +1308,12 @@ const ACTOR_NAMES: [(Item, &str); 45] = [
     (Fairy, "GtEvBottleFairy"),
     (Bee, "GtEvBottleBee"),
     (GoldenBee, "GtEvBottleBee"),
+    (ItemRentalBomb, "KeyBoss"), // Multiworld placeholders
+    (ItemRentalHammer, "KeyBoss"),
+    (ItemRentalBow, "KeyBoss"),
+    (ItemRentalShield, "KeyBoss"),
+    (ItemRentalSandRodFirst, "KeyBoss"),
+    (PackageSword, "KeyBoss"),
 ];
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -1193,7 +1341,7 @@ const ITEM_NAME_OFFSETS: [(Item, u32); 20] = [
     (RupeeGold, 0x6f9be2),       // item_name_sandrod_rental
 ];
 
-const ITEM_NAMES: [(Item, &str); 57] = [
+const ITEM_NAMES: [(Item, &str); 63] = [
     (BadgeBee, "beebadge"),
     (Compass, "compass"),
     (ItemBell, "bell"),
@@ -1251,6 +1399,12 @@ const ITEM_NAMES: [(Item, &str); 57] = [
     (Fairy, "fairy"),
     (Bee, "bee"),
     (GoldenBee, "goldenbee"),
+    (ItemRentalBomb, "bomb_rental"), // Multiworld placeholders
+    (ItemRentalHammer, "hammer_rental"),
+    (ItemRentalBow, "bow_rental"),
+    (ItemRentalShield, "shield_rental"),
+    (ItemRentalSandRodFirst, "sandrod_rental_first"),
+    (PackageSword, "package_sword"),
 ];
 
 const EVENT_FLAG_PTR: u32 = 0x70B728;
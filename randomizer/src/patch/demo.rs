@@ -1,6 +1,7 @@
 use crate::SeedInfo;
 use game::Course::*;
 use log::info;
+use modinfo::settings::boss_hunt::BossHunt;
 use modinfo::settings::cracks::Cracks;
 use modinfo::settings::cracksanity::Cracksanity;
 use modinfo::settings::trials_door::TrialsDoor;
@@ -14,12 +15,10 @@ use rom::{Demo, File};
 pub(crate) fn build_replacement_cutscenes(seed_info: &SeedInfo) -> crate::Result<Vec<File<Demo>>> {
     info!("Building Replacement Cutscenes...");
 
-    const INITIAL_SPAWN: SpawnPoint = SpawnPoint { course: IndoorLight, scene: 1, spawn: 1 };
-
-    // Demo1 - Link's Nightmare cutscene (goes to Link's House 0)
+    // Demo1 - Link's Nightmare cutscene (goes to the chosen start, Link's House 0 by default)
     let mut demo1 = Demo::new();
     get_initial_flags_to_set(seed_info).iter().for_each(|&flag| demo1.set_event_flag(0, flag));
-    demo1.finish(0, INITIAL_SPAWN);
+    demo1.finish(0, seed_info.start.get_spawn_point());
 
     // Demo2 - Sanctuary cutscene (goes to Link's House 2)
     let mut demo2 = Demo::new();
@@ -149,7 +148,7 @@ fn get_initial_flags_to_set(SeedInfo { trials_config, settings, .. }: &SeedInfo)
     }
 
     // Enable opening Lorule Castle from start
-    if settings.lc_requirement == 0 {
+    if settings.lc_requirement == 0 && settings.boss_hunt != BossHunt::LoruleCastle {
         flags.push(670);
     }
 
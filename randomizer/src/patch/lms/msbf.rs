@@ -2,6 +2,7 @@ use crate::patch::Patcher;
 use crate::{Result, SeedInfo};
 use game::Course;
 use log::info;
+use modinfo::settings::boss_hunt::{BossHunt, BOSS_HUNT_BOSSES};
 use modinfo::settings::keysy::Keysy;
 use modinfo::Settings;
 use rom::string_constants;
@@ -369,8 +370,16 @@ fn patch_hyrule_castle_zelda(patcher: &mut Patcher) -> Result<()> {
     Ok(())
 }
 
+/// More Portraits than can ever be collected, as each Boss Hunt boss guards at most one.
+const UNREACHABLE_PORTRAITS: u32 = BOSS_HUNT_BOSSES as u32 + 1;
+
 fn patch_lorule_castle_requirements(patcher: &mut Patcher, settings: &Settings) -> Result<()> {
-    let lc_requirement = settings.lc_requirement as u32;
+    // Boss Hunt opens Lorule Castle from code instead, so no number of Portraits can
+    let lc_requirement = if settings.boss_hunt == BossHunt::LoruleCastle {
+        UNREACHABLE_PORTRAITS
+    } else {
+        settings.lc_requirement as u32
+    };
 
     apply!(patcher,
 
@@ -5,6 +5,7 @@ use crate::filler::filler_item::Item::{
 use crate::{
     hints::{formatting::*, Hint},
     patch::messages::{hint_ghosts::HintGhost, msbt::load_msbt},
+    patch::util::remote_items,
     regions, DashMap, Patcher, Result, SeedInfo,
 };
 use game::Course::{self, *};
@@ -22,8 +23,8 @@ pub fn patch_messages(patcher: &mut Patcher, seed_info: &SeedInfo) -> Result<()>
     // patch_flavor_text(patcher, seed_info)?; // FIXME breaks Maiamai Map
     patch_file_select(patcher, seed_info)?;
     // patch_pause_screen(patcher)?; TODO
-    patch_item_names(patcher)?;
-    patch_event_item_get(patcher)?;
+    patch_item_names(patcher, seed_info)?;
+    patch_event_item_get(patcher, seed_info)?;
     patch_collect(patcher, seed_info)?;
     patch_actions(patcher)?;
     patch_ravio(patcher, seed_info)?;
@@ -35,6 +36,7 @@ pub fn patch_messages(patcher: &mut Patcher, seed_info: &SeedInfo) -> Result<()>
 
     patch_street_merchant(patcher, seed_info)?;
     patch_sahasrahla(patcher, seed_info)?;
+    patch_pedestal_sign(patcher, seed_info)?;
     patch_hint_ghosts(patcher, seed_info)?;
     // patch_mother_maiamai_sign(patcher, seed_info)?;
     patch_bow_of_light(patcher, seed_info)?;
@@ -59,10 +61,13 @@ fn patch_flavor_text(patcher: &mut Patcher, seed_info: &SeedInfo) -> Result<()>
 
 /// File Select Screen
 fn patch_file_select(patcher: &mut Patcher, seed_info: &SeedInfo) -> Result<()> {
+    let summary =
+        format!("{}\nPedestal: {}", seed_info.hash.item_hash, seed_info.settings.ped_requirement.description());
+
     let mut file_select_b = load_msbt(patcher, LanguageBoot, "Mn_FileSelectB")?;
-    file_select_b.set("HeadLineText_00", &seed_info.hash.item_hash);
-    file_select_b.set("HeadLineText_01", &seed_info.hash.item_hash);
-    file_select_b.set("HeadLineText_10", &seed_info.hash.item_hash);
+    file_select_b.set("HeadLineText_00", &summary);
+    file_select_b.set("HeadLineText_01", &summary);
+    file_select_b.set("HeadLineText_10", &summary);
     patcher.update(file_select_b.dump())?;
 
     // let mut file_select_t = load_msbt(patcher, LanguageBoot, "Mn_FileSelectT")?;
@@ -85,7 +90,7 @@ fn patch_pause_screen(patcher: &mut Patcher) -> Result<()> {
 }
 
 /// Item Names
-fn patch_item_names(patcher: &mut Patcher) -> Result<()> {
+fn patch_item_names(patcher: &mut Patcher, seed_info: &SeedInfo) -> Result<()> {
     // Item names in textboxes
     let mut item_name = load_msbt(patcher, LanguageBoot, "ItemName")?;
 
@@ -101,6 +106,11 @@ fn patch_item_names(patcher: &mut Patcher) -> Result<()> {
     // Quake - Repurpose
     item_name.set("item_name_gamecoin", "Quake");
 
+    // Multiworld - Repurpose unused strings as the names of the other players' items
+    for (player, (_, label, _)) in remote_items(seed_info) {
+        item_name.set(label, &format!("{}'s Item", player));
+    }
+
     patcher.update(item_name.dump())?;
 
     // Item descriptions when picked up
@@ -117,7 +127,7 @@ fn patch_item_names(patcher: &mut Patcher) -> Result<()> {
 }
 
 /// Item Descriptions
-fn patch_event_item_get(patcher: &mut Patcher) -> Result<()> {
+fn patch_event_item_get(patcher: &mut Patcher, seed_info: &SeedInfo) -> Result<()> {
     let mut msbt = load_msbt(patcher, LanguageBoot, "EventItemGet")?;
 
     msbt.set("none", "A quake shakes the kingdom!"); // ehh
@@ -135,6 +145,10 @@ fn patch_event_item_get(patcher: &mut Patcher) -> Result<()> {
     msbt.set("kandelaar", "You got the lamp!");
     msbt.set("zelda_amulet", &format!("You got a special charm!\nIt's {}!", attention("useless"))); // Cut " from Princess Zelda"
 
+    for (player, (_, _, label)) in remote_items(seed_info) {
+        msbt.set(label, &format!("You found an item for {}!", name(&player.to_string())));
+    }
+
     patcher.update(msbt.dump())?;
 
     Ok(())
@@ -221,7 +235,8 @@ fn dungeon_article(dungeon_name: &str) -> String {
     match dungeon_name {
         "Skull Woods" | "Turtle Rock" => "",
         _ => " the",
-    }.to_string()
+    }
+    .to_string()
 }
 
 /// Impa in Hyrule Castle dialogue
@@ -253,7 +268,7 @@ fn patch_great_rupee_fairy(patcher: &mut Patcher) -> Result<()> {
 }
 
 /// Treacherous Tower
-fn patch_treacherous_tower(patcher: &mut Patcher, SeedInfo { settings, .. }: &SeedInfo) -> Result<()> {
+fn patch_treacherous_tower(patcher: &mut Patcher, SeedInfo { treacherous_tower_floors, .. }: &SeedInfo) -> Result<()> {
     let mut msbt = load_msbt(patcher, FieldDark, "FieldDark_05")?;
 
     msbt.set(
@@ -271,7 +286,7 @@ fn patch_treacherous_tower(patcher: &mut Patcher, SeedInfo { settings, .. }: &Se
         "fd_GameTower_expert_00",
         &format!(
             "Well, well, well! Double boom in the\nroom! I'm so impressed! The\nRandom course has {}!",
-            name(&format!("{} floors", settings.treacherous_tower_floors))
+            name(&format!("{} floors", treacherous_tower_floors.len()))
         ),
     );
 
@@ -308,23 +323,43 @@ fn patch_cross_old_man(patcher: &mut Patcher) -> Result<()> {
 
 /// Street Merchant - Shorten text & show the item names
 fn patch_street_merchant(patcher: &mut Patcher, seed_info: &SeedInfo) -> Result<()> {
-    let item_left =
-        seed_info.layout.get_unsafe("Street Merchant (Left)", regions::hyrule::kakariko::village::SUBREGION).as_str();
-    let item_right =
-        seed_info.layout.get_unsafe("Street Merchant (Right)", regions::hyrule::kakariko::village::SUBREGION).as_str();
+    let item_left = seed_info
+        .layout
+        .get_unsafe("Street Merchant (Left)", regions::hyrule::kakariko::village::SUBREGION)
+        .to_string();
+    let item_right = seed_info
+        .layout
+        .get_unsafe("Street Merchant (Right)", regions::hyrule::kakariko::village::SUBREGION)
+        .to_string();
+
+    // Randomized Shop Prices are written out, instead of read from the stand
+    let price = |check: &str| {
+        seed_info
+            .shop_prices
+            .get(check)
+            .map(|price| attention(&format!("{} Rupees", price)))
+            .unwrap_or_else(|| PRICE.clone())
+    };
+    let price_left = price("Street Merchant (Left)");
+    let price_right = price("Street Merchant (Right)");
 
     let mut street_merchant = load_msbt(patcher, FieldLight, "FieldLight_18")?;
     street_merchant.set(
         "lgt_NpcStand_BottleEmpty_00_select",
-        &format!("That's a {}.\nUseful for a bunch of things.\nHow about {}?{}", name(item_left), *PRICE, *CHOICE_2),
+        &format!(
+            "That's a {}.\nUseful for a bunch of things.\nHow about {}?{}",
+            name(&item_left),
+            price_left,
+            *CHOICE_2
+        ),
     );
 
     street_merchant.set(
         "lgt_NpcStand_ZoraTreasure_00_select",
         &format!(
             "Ah, yes! A {}\nof remarkable quality. Smooth as silk!\nAnd for you? Only {}!{}",
-            name(item_right),
-            *PRICE,
+            name(&item_right),
+            price_right,
             *CHOICE_2
         ),
     );
@@ -332,7 +367,7 @@ fn patch_street_merchant(patcher: &mut Patcher, seed_info: &SeedInfo) -> Result<
         "lgt_NpcStand_ZoraTreasure_01",
         &format!(
             "Sorry to see it go, actually. I just\ncouldn't stop touching that\nsmooth, smooth {}.",
-            name(item_right)
+            name(&item_right)
         ),
     );
 
@@ -429,6 +464,27 @@ where
     Ok(())
 }
 
+/// Label of the Lost Woods Sign in front of the Master Sword Pedestal
+const PEDESTAL_SIGN: &str = "SNBD_lgt_FieldLight00_Mayoinomori_SignBoard_00";
+
+/// Lost Woods Sign, showing what the Master Sword Pedestal requires
+fn patch_pedestal_sign(patcher: &mut Patcher, seed_info: &SeedInfo) -> Result<()> {
+    let mut msbt = load_msbt(patcher, FieldLight, "FieldLight_00")?;
+    if msbt.get(PEDESTAL_SIGN).is_none() {
+        return Err(crate::Error::game(format!("Lost Woods Sign \"{}\" not found in FieldLight_00", PEDESTAL_SIGN)));
+    }
+
+    let text = format!(
+        "The {} requires:\n{}.",
+        name("Master Sword"),
+        attention(&seed_info.settings.ped_requirement.description())
+    );
+    msbt.set(PEDESTAL_SIGN, &text);
+    patcher.update(msbt.dump())?;
+
+    Ok(())
+}
+
 /// Mother Maiamai Sign
 #[allow(unused)]
 fn patch_mother_maiamai_sign(patcher: &mut Patcher, seed_info: &SeedInfo) -> Result<()> {
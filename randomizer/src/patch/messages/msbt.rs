@@ -22,7 +22,6 @@ pub(crate) struct MsbtFile {
 
 impl MsbtFile {
     /// Looks up and returns the message specified by `label`
-    #[allow(unused)]
     pub(crate) fn get(&self, label: &str) -> Option<&String> {
         return if let Some(item_index) = self.get_item_index(label) {
             self.txt2.messages.get(item_index)
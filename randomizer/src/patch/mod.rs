@@ -1,4 +1,5 @@
 use crate::filler::cracks::Crack;
+use crate::filler::dungeon_entrances::DungeonEntrance;
 use crate::filler::filler_item::{Randomizable, Vane};
 use crate::{patch::util::*, Error, Result, SeedInfo};
 use code::Code;
@@ -13,7 +14,7 @@ use modinfo::settings::weather_vanes::WeatherVanes::*;
 use path_absolutize::*;
 use rom::byaml::scene_env::SceneEnvFile;
 use rom::flag::Flag;
-use rom::scene::{Transform, Vec3};
+use rom::scene::{SpawnPoint, Transform, Vec3};
 use rom::{
     flow::FlowMut,
     scene::{Arg, Obj, Rail, SceneMeta},
@@ -21,7 +22,11 @@ use rom::{
 };
 use serde::Serialize;
 use std::ops::Add;
-use std::{collections::HashMap, fs, path::Path};
+use std::{
+    collections::{HashMap, HashSet},
+    fs,
+    path::Path,
+};
 use tempfile::tempdir;
 use try_insert_ext::EntryInsertExt;
 
@@ -53,8 +58,14 @@ pub struct Patcher {
     game: Rom,
     boot: Language,
     rentals: [Item; 9],
+    /// The price of each of Ravio's items, if shop prices are randomized.
+    rental_prices: [Option<u16>; 9],
     merchant: [Item; 3],
     courses: HashMap<CourseId, Course>,
+    /// Opened flags of the chests holding non-major items, by course. Used by Informational Compasses.
+    minor_chests: HashMap<CourseId, Vec<(u8, u16)>>,
+    /// Opened flag of the chest holding each course's Compass. Used by Informational Compasses.
+    compass_chests: HashMap<CourseId, Flag>,
 }
 
 impl Patcher {
@@ -64,8 +75,11 @@ impl Patcher {
             game,
             boot,
             rentals: [Item::KeySmall; 9],
+            rental_prices: [None; 9],
             merchant: [Item::KeySmall; 3],
             courses: Default::default(),
+            minor_chests: Default::default(),
+            compass_chests: Default::default(),
         })
     }
 
@@ -259,7 +273,7 @@ impl Patcher {
     }
 
     /// Perform patching operations for each patch, depending on what type it is.
-    fn apply<F>(&mut self, patch: Patch, seed_info: &SeedInfo, filler_item: F) -> Result<()>
+    fn apply<F>(&mut self, patch: Patch, seed_info: &SeedInfo, check: &str, filler_item: F) -> Result<()>
     where
         F: Into<Option<Randomizable>> + Clone,
     {
@@ -274,7 +288,8 @@ impl Patcher {
             | Patch::Key { course, scene, unq }
             | Patch::SilverRupee { course, scene, unq }
             | Patch::GoldRupee { course, scene, unq } => {
-                self.parse_args(course, scene, unq).1 = filler_item.into().unwrap().as_item_index() as i32;
+                self.parse_args(course, scene, unq).1 =
+                    filler_item.into().unwrap().as_item_index(seed_info.player) as i32;
             },
             Patch::Crack { course, scene, unq, crack } => {
                 self.patch_crack(course, scene + 1, unq, crack, seed_info)?;
@@ -283,7 +298,8 @@ impl Patcher {
                 self.patch_weather_vane(filler_item.into().unwrap(), course, scene, unq, vane, seed_info)?;
             },
             Patch::Maiamai { course, scene, unq } => {
-                self.parse_args(course, scene, unq).2 = filler_item.into().unwrap().as_item_index() as i32;
+                self.parse_args(course, scene, unq).2 =
+                    filler_item.into().unwrap().as_item_index(seed_info.player) as i32;
             },
             Patch::Event { course, name, index } => {
                 self.flow(course)?
@@ -299,17 +315,18 @@ impl Patcher {
                             index
                         ))
                     })?
-                    .set_value(filler_item.into().unwrap().as_item_index());
+                    .set_value(filler_item.into().unwrap().as_item_index(seed_info.player));
             },
             Patch::Shop(Shop::Ravio(index)) => {
-                self.rentals[index as usize] = filler_item.into().unwrap().as_item().unwrap().to_game_item();
+                self.rentals[index as usize] = filler_item.into().unwrap().normalize(seed_info.player);
+                self.rental_prices[index as usize] = seed_info.shop_prices.get(check).copied();
             },
             Patch::Shop(Shop::Merchant(index)) => {
-                self.merchant[index as usize] = filler_item.into().unwrap().as_item().unwrap().to_game_item();
+                self.merchant[index as usize] = filler_item.into().unwrap().normalize(seed_info.player);
             },
             Patch::Multi(patches) => {
                 for patch in patches {
-                    self.apply(patch, seed_info, filler_item.clone())?;
+                    self.apply(patch, seed_info, check, filler_item.clone())?;
                 }
             },
             Patch::None => {},
@@ -331,10 +348,28 @@ impl Patcher {
     /// Patch Chest actors and swap their size if needed for CSMC.
     fn prep_chest(
         &mut self, item: Randomizable, course: CourseId, stage: u16, unq: u16, is_big: bool,
-        SeedInfo { settings, .. }: &SeedInfo,
+        SeedInfo { settings, player, .. }: &SeedInfo,
     ) -> Result<()> {
         // Set contents
-        self.parse_args(course, stage, unq).0 = item.as_item_index() as i32;
+        let args = self.parse_args(course, stage, unq);
+        args.0 = item.as_item_index(*player) as i32;
+        let opened_flag = (args.5, args.7);
+
+        if settings.informational_compasses {
+            // The game counts a Compass for whichever dungeon it's picked up in, so each one must stay in its own
+            if item.normalize(*player) == Item::Compass {
+                let flag = Flag::from_pair(opened_flag.0, opened_flag.1).ok_or_else(|| {
+                    Error::game(format!("Compass chest {}{} [{}] has no opened flag", course.as_str(), stage + 1, unq))
+                })?;
+                if self.compass_chests.insert(course, flag).is_some() {
+                    return Err(Error::game(format!("More than one Compass chest in {}", course.as_str())));
+                }
+            }
+
+            if !item.is_major_item() {
+                self.minor_chests.entry(course).or_default().push(opened_flag);
+            }
+        }
 
         let small_chest = (35, "TreasureBoxS");
         let large_chest = (34, "TreasureBoxL");
@@ -365,6 +400,9 @@ impl Patcher {
     }
 
     /// Cracks!
+    ///
+    /// Each Crack is patched from its own CrackMap entry alone, so one-way links from Decoupled Cracksanity need no
+    /// special handling.
     fn patch_crack(
         &mut self, course: CourseId, scene: u16, unq: u16, here_crack: Crack, seed_info: &SeedInfo,
     ) -> Result<()> {
@@ -559,7 +597,8 @@ impl Patcher {
             _ => unreachable!(),
         };
 
-        // Set Weather Vane flag to randomized value
+        // Set Weather Vane flag to randomized value. Only the flag moves: Bell travel still lands at each vane's own
+        // spawn point, as the warp destinations aren't part of the vane objects and have no patch point here yet.
         self.parse_args(course, scene, unq).6 = wv_flag;
 
         let wv_world_dest = Vane::get_world(item.into());
@@ -590,16 +629,127 @@ impl Patcher {
         Ok(())
     }
 
+    /// Dungeon Entrances
+    ///
+    /// The loading zones are found by their destination rather than by UNQ: each entrance's main door arrives at
+    /// spawn point 0 of the dungeon, and each dungeon's main exit leads back to the entrance's overworld scene.
+    fn patch_dungeon_entrances(&mut self, seed_info: &SeedInfo) -> Result<()> {
+        let shuffled = seed_info
+            .dungeon_entrance_map
+            .iter()
+            .filter(|(entrance, dungeon)| entrance != dungeon)
+            .map(|(&entrance, &dungeon)| (entrance, dungeon))
+            .collect::<Vec<_>>();
+        if shuffled.is_empty() {
+            return Ok(());
+        }
+
+        // Locate every vanilla loading zone before any of them are redirected
+        let mut entries = HashMap::new();
+        let mut exits = HashMap::new();
+        for entrance in DungeonEntrance::ALL {
+            let (ow_course, ow_scene) = entrance.get_overworld_scene();
+            let (dungeon_course, dungeon_scene) = entrance.get_dungeon_scene();
+            entries.insert(
+                entrance,
+                self.find_loading_zones((ow_course, ow_scene), (dungeon_course, dungeon_scene), Some(0))?,
+            );
+            exits.insert(
+                entrance,
+                self.find_loading_zones(
+                    (dungeon_course, dungeon_scene),
+                    (ow_course, ow_scene),
+                    entrance.get_known_exit_spawn(),
+                )?,
+            );
+        }
+
+        for (entrance, dungeon) in shuffled {
+            let (ow_course, ow_scene) = entrance.get_overworld_scene();
+            let (dungeon_course, dungeon_scene) = dungeon.get_dungeon_scene();
+
+            // Entrance -> Dungeon
+            let (entry_unqs, _) = entries.get(&entrance).unwrap();
+            let (_, entry_spawn) = entries.get(&dungeon).unwrap();
+            let sp = SpawnPoint::new(dungeon_course, dungeon_scene as i32, *entry_spawn);
+            self.modify_objs(ow_course, ow_scene, entry_unqs.iter().map(|&unq| redirect(unq, sp)).collect::<Vec<_>>());
+
+            // Dungeon -> Entrance
+            let (exit_unqs, _) = exits.get(&dungeon).unwrap();
+            let (_, exit_spawn) = exits.get(&entrance).unwrap();
+            let sp = SpawnPoint::new(ow_course, ow_scene as i32, *exit_spawn);
+            self.modify_objs(
+                dungeon_course,
+                dungeon_scene,
+                exit_unqs.iter().map(|&unq| redirect(unq, sp)).collect::<Vec<_>>(),
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Checks the chosen start's spawn point exists in its scene, so the opening cutscene can't strand Link in the void.
+    fn check_start_spawn_point(&mut self, seed_info: &SeedInfo) -> Result<()> {
+        let SpawnPoint { course, scene, spawn } = seed_info.start.get_spawn_point();
+        let spawns = self.scene(course, scene as u16 - 1)?.stage().get().find_spawn_points();
+        if !spawns.contains(&spawn) {
+            return Err(Error::game(format!(
+                "Start \"{}\" uses spawn point {} in {}{}, which doesn't exist. Found: {:?}",
+                seed_info.start.as_str(),
+                spawn,
+                course.as_str(),
+                scene,
+                spawns
+            )));
+        }
+        Ok(())
+    }
+
+    /// Finds the UNQs of all loading zones in one scene leading into another, along with their shared spawn point.
+    fn find_loading_zones(
+        &mut self, (course, scene): (CourseId, u16), (dest_course, dest_scene): (CourseId, u16), spawn: Option<i32>,
+    ) -> Result<(Vec<u16>, i32)> {
+        let redirects = self
+            .scene(course, scene - 1)?
+            .stage()
+            .get()
+            .find_redirects(dest_course, dest_scene as i32)
+            .into_iter()
+            .filter(|(_, sp)| spawn.is_none_or(|spawn| spawn == *sp))
+            .collect::<Vec<_>>();
+
+        let spawns = redirects.iter().map(|(_, sp)| *sp).collect::<HashSet<_>>();
+        if spawns.len() != 1 {
+            return Err(Error::game(format!(
+                "Expected loading zones in {}{} leading to exactly one spawn point in {}{}, found: {:?}",
+                course.as_str(),
+                scene,
+                dest_course.as_str(),
+                dest_scene,
+                redirects
+            )));
+        }
+
+        let sp = *spawns.iter().next().unwrap();
+        Ok((redirects.into_iter().map(|(unq, _)| unq).collect(), sp))
+    }
+
     pub fn prepare(mut self, seed_info: &SeedInfo) -> Result<Patches> {
         actors::patch(&mut self, seed_info)?;
         lms::msbf::patch(&mut self, seed_info)?;
         messages::patch_messages(&mut self, seed_info)?;
         let prizes = get_dungeon_prizes(&seed_info.layout);
+        // Boss Hunt can only tell a required boss is defeated from its dungeon's prize flag
+        if let Some(boss) = seed_info.required_bosses.iter().find(|&&boss| boss_flag(boss, &prizes).is_none()) {
+            return Err(Error::game(format!("{} doesn't guard a Pendant or Portrait for Boss Hunt", boss.as_str())));
+        }
         prizes::patch_dungeon_prizes(&mut self, &prizes);
         // byaml::get_item::patch(&mut self)?;
         byaml::course::patch(&mut self, &prizes, seed_info);
         byaml::stage::patch(&mut self, seed_info)?;
+        self.patch_dungeon_entrances(seed_info)?;
         let scene_env_file = byaml::scene_env::patch(&mut self, &seed_info.settings);
+        self.check_start_spawn_point(seed_info)?;
         let cutscenes = demo::build_replacement_cutscenes(seed_info)?;
 
         let common_archive = self.game.common()?;
@@ -701,11 +851,11 @@ pub enum Patch {
 }
 
 impl Patch {
-    pub fn apply<F>(self, patcher: &mut Patcher, seed_info: &SeedInfo, filler_item: F) -> Result<()>
+    pub fn apply<F>(self, patcher: &mut Patcher, seed_info: &SeedInfo, check: &str, filler_item: F) -> Result<()>
     where
         F: Into<Option<Randomizable>>,
     {
-        patcher.apply(self, seed_info, filler_item.into())
+        patcher.apply(self, seed_info, check, filler_item.into())
     }
 }
 
@@ -2,10 +2,11 @@
 
 use super::DungeonPrizes;
 use crate::filler::filler_item;
-use crate::filler::filler_item::Randomizable;
 use crate::filler::filler_item::Randomizable::Item;
-use crate::{regions, Layout};
+use crate::filler::filler_item::{Goal, Randomizable};
+use crate::{regions, Layout, PlayerId, SeedInfo};
 use macros::fail;
+use modinfo::settings::MAX_PLAYERS;
 use rom::flag::Flag;
 use rom::scene::{Obj, Rail, SpawnPoint, Vec3};
 
@@ -132,3 +133,56 @@ pub(crate) fn get_dungeon_prizes(layout: &Layout) -> DungeonPrizes {
         ir_prize: layout.get_unsafe("[IR] Prize", regions::dungeons::ice::ruins::SUBREGION),
     }
 }
+
+/// The flag marking a Boss Hunt boss as defeated. The game keeps no boss-defeat flag we can read, so this is the flag of
+/// the Pendant or Portrait on its dungeon's Prize check, which only appears once the boss has fallen.
+///
+/// Returns `None` if the boss isn't a Boss Hunt boss, or if its Prize check doesn't hold a Pendant or Portrait.
+pub(crate) fn boss_flag(boss: Goal, prizes: &DungeonPrizes) -> Option<Flag> {
+    let prize = match boss {
+        Goal::Yuga => prizes.ep_prize,
+        Goal::Margomill => prizes.hg_prize,
+        Goal::Moldorm => prizes.th_prize,
+        Goal::GemesaurKing => prizes.pd_prize,
+        Goal::Arrghus => prizes.sp_prize,
+        Goal::Knucklemaster => prizes.sw_prize,
+        Goal::Stalblind => prizes.tt_prize,
+        Goal::Grinexx => prizes.tr_prize,
+        Goal::Zaganaga => prizes.dp_prize,
+        Goal::Dharkstare => prizes.ir_prize,
+        _ => return None,
+    };
+    (is_pendant(prize) || is_sage(prize)).then(|| prize_flag(prize))
+}
+
+/// Get-items the randomizer never hands out, given instead of the items found for other players of a Multiworld seed:
+/// one for each other player, renamed after them. Listed with their ItemName and EventItemGet labels.
+pub(crate) const REMOTE_ITEMS: [(game::Item, &str, &str); MAX_PLAYERS as usize - 1] = [
+    (game::Item::ItemRentalBomb, "item_name_bomb_rental", "item_bomb_rental"),
+    (game::Item::ItemRentalHammer, "item_name_hammer_rental", "item_hammer_rental"),
+    (game::Item::ItemRentalBow, "item_name_bow_rental", "item_bow_rental"),
+    (game::Item::ItemRentalShield, "item_name_shield_rental", "item_shield_rental"),
+    (game::Item::ItemRentalSandRodFirst, "item_name_sandrod_rental_first", "item_sandrod_rental_first"),
+    (game::Item::PackageSword, "item_name_package_sword", "package_sword"),
+    (game::Item::Heart, "item_name_potshop_heart", "potshop_heart"),
+];
+
+/// The index into [`REMOTE_ITEMS`] of the placeholder for the `recipient`'s items found in the `world` player's world.
+/// A world never holds items for its own player, so the slots of the other players are numbered around it.
+pub(crate) fn remote_item_slot(world: PlayerId, recipient: PlayerId) -> usize {
+    if recipient < world {
+        recipient.index()
+    } else {
+        recipient.index() - 1
+    }
+}
+
+/// The other players of the `seed_info` player's Multiworld seed, each with the [`REMOTE_ITEMS`] entry of their items.
+pub(crate) fn remote_items(
+    seed_info: &SeedInfo,
+) -> impl Iterator<Item = (PlayerId, (game::Item, &'static str, &'static str))> + '_ {
+    (0..seed_info.settings.players)
+        .map(PlayerId::new)
+        .filter(|&player| player != seed_info.player)
+        .map(|player| (player, REMOTE_ITEMS[remote_item_slot(seed_info.player, player)]))
+}
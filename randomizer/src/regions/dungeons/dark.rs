@@ -1,6 +1,7 @@
 crate::region! {
     course: DungeonDark,
     name: "Dark Palace",
+    world: Lorule,
     color: Green,
     palace {
         locations: [
@@ -1,6 +1,7 @@
 crate::region! {
     course: DungeonSand,
     name: "Desert Palace",
+    world: Hyrule,
     color: Name,
     palace {
         locations: [
@@ -1,6 +1,7 @@
 crate::region! {
     course: DungeonEast,
     name: "Eastern Palace",
+    world: Hyrule,
     color: Green,
     palace {
         locations: [
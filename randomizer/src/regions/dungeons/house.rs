@@ -1,6 +1,7 @@
 crate::region! {
     course: DungeonWind,
     name: "House of Gales",
+    world: Hyrule,
     color: Blue,
     gales {
         locations: [
@@ -1,6 +1,7 @@
 crate::region! {
     course: IndoorLight,
     name: "Hyrule Castle",
+    world: Hyrule,
     color: Name,
     castle {
         locations: [
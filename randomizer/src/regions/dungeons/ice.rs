@@ -1,6 +1,7 @@
 crate::region! {
     course: DungeonIce,
     name: "Ice Ruins",
+    world: Lorule,
     color: Attention,
     ruins {
         locations: [
@@ -1,6 +1,7 @@
 crate::region! {
     course: DungeonGanon,
     name: "Lorule Castle",
+    world: Lorule,
     color: Purple,
     castle {
         locations: [
@@ -1,6 +1,7 @@
 crate::region! {
     course: DungeonDokuro,
     name: "Skull Woods",
+    world: Lorule,
     color: Blue,
     woods {
         locations: [
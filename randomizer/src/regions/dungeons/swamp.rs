@@ -1,6 +1,7 @@
 crate::region! {
     course: DungeonWater,
     name: "Swamp Palace",
+    world: Lorule,
     color: Beige,
     palace {
         locations: [
@@ -1,6 +1,7 @@
 crate::region! {
     course: DungeonHagure,
     name: "Thieves' Hideout",
+    world: Lorule,
     color: Beige,
     hideout {
         locations: [
@@ -1,6 +1,7 @@
 crate::region! {
     course: DungeonHera,
     name: "Tower of Hera",
+    world: Hyrule,
     color: Attention,
     hera {
         locations: [
@@ -1,6 +1,7 @@
 crate::region! {
     course: DungeonKame,
     name: "Turtle Rock",
+    world: Lorule,
     color: Purple,
     rock {
         locations: [
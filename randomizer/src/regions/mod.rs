@@ -11,6 +11,8 @@ pub struct Subregion {
     name: &'static str,
     color: HintColor,
     world: World,
+    #[serde(skip)]
+    home_world: World,
     id: &'static str,
 }
 
@@ -19,6 +21,11 @@ impl Subregion {
         self.name
     }
 
+    /// The overworld this subregion is found in. Same as [`Self::world`] outside of dungeons.
+    pub fn home_world(&self) -> World {
+        self.home_world
+    }
+
     pub fn name_colorized(&self) -> String {
         self.color.format(self.name)
     }
@@ -139,9 +146,16 @@ pub(crate) fn patch(patcher: &mut Patcher, seed_info: &SeedInfo) -> crate::Resul
 #[doc(hidden)]
 #[macro_export]
 macro_rules! region {
+    (@home_world) => {
+        super::WORLD
+    };
+    (@home_world $home:ident) => {
+        $crate::regions::World::$home
+    };
     (
         course: $course:ident,
         name: $name:literal,
+        $(world: $home:ident,)?
         color: $color:ident,
         $start:ident $start_props:tt,
         $($id:ident $props:tt,)*
@@ -161,6 +175,7 @@ macro_rules! region {
         pub const COLOR: $crate::hints::hint_color::HintColor = $crate::hints::hint_color::HintColor::$color;
         #[allow(unused)]
         pub const COURSE: ::game::Course = ::game::Course::$course;
+        pub const HOME_WORLD: $crate::regions::World = $crate::region!(@home_world $($home)?);
     };
 }
 
@@ -187,6 +202,7 @@ macro_rules! subregion {
                 name: super::NAME,
                 color: super::COLOR,
                 world: super::super::WORLD,
+                home_world: super::HOME_WORLD,
                 id: stringify!($id),
             };
 
@@ -197,6 +213,7 @@ macro_rules! subregion {
                 $($crate::patch!($variant $props).apply(
                     patcher,
                     seed_info,
+                    $key,
                     seed_info.layout
                         .get($key, SUBREGION)
                 )?;)*)?
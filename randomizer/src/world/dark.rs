@@ -1,3 +1,4 @@
+use crate::filler::bosses::BossArena;
 use crate::filler::check::Check;
 use crate::filler::filler_item::Goal;
 use crate::filler::location::Location::{self, *};
@@ -74,7 +75,11 @@ pub(crate) fn graph() -> HashMap<Location, LocationNode> {
         ),
         (
             DarkPalaceBoss,
-            location("Dark Palace Boss", None, vec![edge!(DarkPalaceAfterBoss, |p| p.can_defeat_gemesaur())]),
+            location(
+                "Dark Palace Boss",
+                None,
+                vec![edge!(DarkPalaceAfterBoss, |p| p.can_defeat_boss_in(BossArena::DarkPalace))],
+            ),
         ),
         (
             DarkPalaceAfterBoss,
@@ -1,3 +1,4 @@
+use crate::filler::bosses::{Boss, BossArena};
 use crate::filler::check::Check;
 use crate::filler::filler_item::Goal;
 use crate::filler::location::Location::{self, *};
@@ -201,9 +202,9 @@ pub(crate) fn graph(crack_map: &CrackMap) -> HashMap<Location, LocationNode> {
                     crack_left(Zaganaga, crack_map, false),
                     crack_right(Zaganaga, crack_map, false),
                     edge!(MiseryMireRewardBasket => {
-                        normal: |p| p.has_sand_rod() && p.hearts(9.0) && (p.has_master_sword() || (p.swordless_mode() && p.can_attack())),
-                        hard: |p| p.has_sand_rod() && p.can_attack(),
-                        hell: |p| p.has_bow() || p.has_master_sword(),
+                        normal: |p| p.can_defeat_boss_in(BossArena::DesertPalace),
+                        hard: |p| p.get_boss(BossArena::DesertPalace) == Boss::Zaganaga && p.has_sand_rod() && p.can_attack(),
+                        hell: |p| p.get_boss(BossArena::DesertPalace) == Boss::Zaganaga && (p.has_bow() || p.has_master_sword()),
                     }),
                 ],
             ),
@@ -1,3 +1,4 @@
+use crate::filler::bosses::BossArena;
 use crate::filler::check::Check;
 use crate::filler::filler_item::Goal;
 use crate::filler::location::Location::{self, *};
@@ -108,7 +109,14 @@ pub(crate) fn graph() -> HashMap<Location, LocationNode> {
             location(
                 "Tower of Hera Boss",
                 vec![],
-                vec![old_path(TowerOfHeraPostBoss, Some(|p| p.can_defeat_moldorm()), None, None, None, None)],
+                vec![old_path(
+                    TowerOfHeraPostBoss,
+                    Some(|p| p.can_defeat_boss_in(BossArena::TowerOfHera)),
+                    None,
+                    None,
+                    None,
+                    None,
+                )],
             ),
         ),
         (
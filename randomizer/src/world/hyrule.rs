@@ -22,18 +22,31 @@ pub(crate) fn graph(crack_map: &CrackMap) -> HashMap<Location, LocationNode> {
                 "Ravio's Shop",
                 vec![
                     check!("Ravio's Gift", regions::hyrule::ravio::shop::SUBREGION),
-                    check!("Ravio's Shop (1)", regions::hyrule::ravio::shop::SUBREGION, |p| p.is_ravio_shop_open()),
-                    check!("Ravio's Shop (2)", regions::hyrule::ravio::shop::SUBREGION, |p| p.is_ravio_shop_open()),
-                    check!("Ravio's Shop (3)", regions::hyrule::ravio::shop::SUBREGION, |p| p.is_ravio_shop_open()),
-                    check!("Ravio's Shop (4)", regions::hyrule::ravio::shop::SUBREGION, |p| p.is_ravio_shop_open()),
-                    check!("Ravio's Shop (5)", regions::hyrule::ravio::shop::SUBREGION, |p| p.is_ravio_shop_open()
-                        || p.has_seen_ravio_signs()),
-                    check!("Ravio's Shop (6)", regions::hyrule::ravio::shop::SUBREGION, |p| p.has_sage_osfala()),
-                    check!("Ravio's Shop (7)", regions::hyrule::ravio::shop::SUBREGION, |p| p.is_ravio_shop_open()),
-                    check!("Ravio's Shop (8)", regions::hyrule::ravio::shop::SUBREGION, |p| p.is_ravio_shop_open()),
-                    check!("Ravio's Shop (9)", regions::hyrule::ravio::shop::SUBREGION, |p| p.is_ravio_shop_open()),
+                    check!("Ravio's Shop (1)", regions::hyrule::ravio::shop::SUBREGION, |p| p.is_ravio_shop_open()
+                        && p.can_buy_shop_item("Ravio's Shop (1)")),
+                    check!("Ravio's Shop (2)", regions::hyrule::ravio::shop::SUBREGION, |p| p.is_ravio_shop_open()
+                        && p.can_buy_shop_item("Ravio's Shop (2)")),
+                    check!("Ravio's Shop (3)", regions::hyrule::ravio::shop::SUBREGION, |p| p.is_ravio_shop_open()
+                        && p.can_buy_shop_item("Ravio's Shop (3)")),
+                    check!("Ravio's Shop (4)", regions::hyrule::ravio::shop::SUBREGION, |p| p.is_ravio_shop_open()
+                        && p.can_buy_shop_item("Ravio's Shop (4)")),
+                    check!("Ravio's Shop (5)", regions::hyrule::ravio::shop::SUBREGION, |p| (p.is_ravio_shop_open()
+                        || p.has_seen_ravio_signs())
+                        && p.can_buy_shop_item("Ravio's Shop (5)")),
+                    check!("Ravio's Shop (6)", regions::hyrule::ravio::shop::SUBREGION, |p| p.has_sage_osfala()
+                        && p.can_buy_shop_item("Ravio's Shop (6)")),
+                    check!("Ravio's Shop (7)", regions::hyrule::ravio::shop::SUBREGION, |p| p.is_ravio_shop_open()
+                        && p.can_buy_shop_item("Ravio's Shop (7)")),
+                    check!("Ravio's Shop (8)", regions::hyrule::ravio::shop::SUBREGION, |p| p.is_ravio_shop_open()
+                        && p.can_buy_shop_item("Ravio's Shop (8)")),
+                    check!("Ravio's Shop (9)", regions::hyrule::ravio::shop::SUBREGION, |p| p.is_ravio_shop_open()
+                        && p.can_buy_shop_item("Ravio's Shop (9)")),
+                ],
+                vec![
+                    edge!(HyruleField),
+                    // Boss Hunt: completing the hunt beats the game wherever the player is
+                    edge!(SacredRealm, |p| p.has_completed_boss_hunt()),
                 ],
-                vec![edge!(HyruleField)],
             ),
         ),
         (
@@ -106,9 +119,11 @@ pub(crate) fn graph(crack_map: &CrackMap) -> HashMap<Location, LocationNode> {
                     ),
                     check!("Bird Lover", regions::hyrule::eastern::ruins::SUBREGION, |p| p.has_flippers()),
                     // Kakariko Village
-                    check!("Street Merchant (Left)", regions::hyrule::kakariko::village::SUBREGION),
+                    check!("Street Merchant (Left)", regions::hyrule::kakariko::village::SUBREGION, |p| p
+                        .can_buy_shop_item("Street Merchant (Left)")),
                     check!("Street Merchant (Right)", regions::hyrule::kakariko::village::SUBREGION, |p| p
-                        .has_shady_guy_trigger()),
+                        .has_shady_guy_trigger()
+                        && p.can_buy_shop_item("Street Merchant (Right)")),
                     check!("Shady Guy", regions::hyrule::kakariko::village::SUBREGION, |p| p.has_shady_guy_trigger()
                         && (p.can_merge() || p.has_boots())),
                     check!("Dodge the Cuccos", regions::hyrule::kakariko::village::SUBREGION),
@@ -374,25 +389,24 @@ pub(crate) fn graph(crack_map: &CrackMap) -> HashMap<Location, LocationNode> {
             location(
                 "Mother Maiamai Cave",
                 vec![
-                    check!("Maiamai Bow Upgrade", regions::hyrule::lake::cave::SUBREGION, |p| p.has_90_maiamai()
-                        && p.has_bow()),
-                    check!("Maiamai Boomerang Upgrade", regions::hyrule::lake::cave::SUBREGION, |p| p.has_90_maiamai()
-                        && p.has_boomerang()),
-                    check!("Maiamai Hookshot Upgrade", regions::hyrule::lake::cave::SUBREGION, |p| p.has_90_maiamai()
-                        && p.has_hookshot()),
-                    check!("Maiamai Hammer Upgrade", regions::hyrule::lake::cave::SUBREGION, |p| p.has_90_maiamai()
-                        && p.has_hammer()),
-                    check!("Maiamai Bombs Upgrade", regions::hyrule::lake::cave::SUBREGION, |p| p.has_90_maiamai()
-                        && p.has_bombs()),
-                    check!("Maiamai Fire Rod Upgrade", regions::hyrule::lake::cave::SUBREGION, |p| p.has_90_maiamai()
-                        && p.has_fire_rod()),
-                    check!("Maiamai Ice Rod Upgrade", regions::hyrule::lake::cave::SUBREGION, |p| p.has_90_maiamai()
-                        && p.has_ice_rod()),
+                    check!("Maiamai Bow Upgrade", regions::hyrule::lake::cave::SUBREGION, |p| p
+                        .can_get_maiamai_reward(1)),
+                    check!("Maiamai Boomerang Upgrade", regions::hyrule::lake::cave::SUBREGION, |p| p
+                        .can_get_maiamai_reward(2)),
+                    check!("Maiamai Hookshot Upgrade", regions::hyrule::lake::cave::SUBREGION, |p| p
+                        .can_get_maiamai_reward(3)),
+                    check!("Maiamai Hammer Upgrade", regions::hyrule::lake::cave::SUBREGION, |p| p
+                        .can_get_maiamai_reward(4)),
+                    check!("Maiamai Bombs Upgrade", regions::hyrule::lake::cave::SUBREGION, |p| p
+                        .can_get_maiamai_reward(5)),
+                    check!("Maiamai Fire Rod Upgrade", regions::hyrule::lake::cave::SUBREGION, |p| p
+                        .can_get_maiamai_reward(6)),
+                    check!("Maiamai Ice Rod Upgrade", regions::hyrule::lake::cave::SUBREGION, |p| p
+                        .can_get_maiamai_reward(7)),
                     check!("Maiamai Tornado Rod Upgrade", regions::hyrule::lake::cave::SUBREGION, |p| p
-                        .has_90_maiamai()
-                        && p.has_tornado_rod()),
-                    check!("Maiamai Sand Rod Upgrade", regions::hyrule::lake::cave::SUBREGION, |p| p.has_90_maiamai()
-                        && p.has_sand_rod()),
+                        .can_get_maiamai_reward(8)),
+                    check!("Maiamai Sand Rod Upgrade", regions::hyrule::lake::cave::SUBREGION, |p| p
+                        .can_get_maiamai_reward(9)),
                     check!("100 Maiamai", regions::hyrule::lake::cave::SUBREGION, |p| p.has_bombs()
                         && p.has_boomerang()
                         && p.has_bow()
@@ -1,13 +1,15 @@
+use crate::filler::interiors::InteriorMap;
 use crate::regions::Subregion;
 use crate::{
     filler::filler_item::Randomizable,
     filler::{check::Check, location::Location, location_node::LocationNode, logic::Logic, progress::Progress},
     hints::hint_ghost_name,
-    CrackMap, DashMap, LocationInfo,
+    CrackMap, DashMap, DungeonEntranceMap, LocationInfo,
 };
 use game::ghosts::HintGhost;
-use log::info;
+use modinfo::settings::logic::LogicMode;
 use serde::Serialize;
+use std::collections::{HashMap, HashSet};
 use std::ops::{Deref, DerefMut};
 
 mod dark;
@@ -34,6 +36,29 @@ impl WorldGraph {
     fn new() -> Self {
         Self { graph: Default::default() }
     }
+
+    /// Finds every Location connected to `start` by paths possible under the given logic mode, regardless of the items
+    /// required to take them.
+    ///
+    /// Bell travel is not followed, as the Weather Vanes it needs may only be activated from the Locations in question.
+    pub(crate) fn find_connected_locations(&self, start: Location, logic_mode: LogicMode) -> HashSet<Location> {
+        let mut visited = HashSet::from([start]);
+        let mut queue = vec![start];
+
+        while let Some(location) = queue.pop() {
+            if matches!(location, Location::HyruleBellTravel | Location::LoruleBellTravel) {
+                continue;
+            }
+
+            for path in self.graph.get(&location).and_then(|node| node.get_paths().as_ref()).into_iter().flatten() {
+                if path.get_logic().is_possible(logic_mode) && visited.insert(path.get_destination()) {
+                    queue.push(path.get_destination());
+                }
+            }
+        }
+
+        visited
+    }
 }
 
 impl Deref for WorldGraph {
@@ -52,8 +77,9 @@ impl DerefMut for WorldGraph {
 
 /// Build the World Graph
 /// FIXME shouldn't take crack_map as argument, map should be independent of that randomization
-pub fn build_world_graph(crack_map: &CrackMap) -> WorldGraph {
-    info!("Building World Graph...");
+pub fn build_world_graph(
+    crack_map: &CrackMap, dungeon_entrance_map: &DungeonEntranceMap, interior_map: &InteriorMap,
+) -> WorldGraph {
     let mut world = WorldGraph::new();
 
     world.extend(hyrule::graph(crack_map));
@@ -75,9 +101,43 @@ pub fn build_world_graph(crack_map: &CrackMap) -> WorldGraph {
 
     world.extend(lorule_castle::graph(crack_map));
 
+    let mut redirects = get_dungeon_entrance_redirects(dungeon_entrance_map);
+    redirects.extend(interior_map.get_redirects());
+    apply_redirects(&mut world, redirects);
+
     world
 }
 
+/// Redirects rewiring the paths between each overworld Dungeon Entrance and the foyer of the dungeon now behind it.
+fn get_dungeon_entrance_redirects(dungeon_entrance_map: &DungeonEntranceMap) -> Vec<(Location, Location, Location)> {
+    let mut redirects = Vec::new();
+    for (&entrance, &dungeon) in dungeon_entrance_map.iter().filter(|(entrance, dungeon)| entrance != dungeon) {
+        redirects.push((entrance.get_outside_location(), entrance.get_foyer_location(), dungeon.get_foyer_location()));
+        redirects.push((dungeon.get_foyer_location(), dungeon.get_exit_location(), entrance.get_exit_location()));
+    }
+    redirects
+}
+
+/// Applies `(node, old destination, new destination)` redirects to the World Graph.
+///
+/// Every redirect of a node is applied at once so each is based on the vanilla graph, rather than chaining onto a path
+/// another redirect already moved.
+fn apply_redirects(world: &mut WorldGraph, redirects: Vec<(Location, Location, Location)>) {
+    let mut by_location: HashMap<Location, HashMap<Location, Location>> = HashMap::new();
+    for (location, from, to) in redirects {
+        if by_location.entry(location).or_default().insert(from, to).is_some() {
+            panic!("Conflicting World Graph redirects from {:?} to {:?}", location, from);
+        }
+    }
+
+    for (location, redirects) in by_location {
+        world
+            .get_mut(&location)
+            .unwrap_or_else(|| panic!("World Graph missing Location: {:?}", location))
+            .redirect_paths(&redirects);
+    }
+}
+
 /// Check convenience macros
 macro_rules! check {
     ($loc_name:expr, $loc_region:expr) => {
@@ -232,7 +292,7 @@ fn crack_left(crack: Crack, crack_map: &CrackMap, is_hc: bool) -> Path {
 
     let logic: fn(&Progress) -> bool = if is_hc { |p| p.can_merge() } else { |p| p.are_cracks_open() && p.can_merge() };
 
-    Path::new(right, *Logic::new().normal(logic))
+    Path::crack(right, *Logic::new().normal(logic))
 }
 
 fn crack_right(crack: Crack, crack_map: &CrackMap, is_hc: bool) -> Path {
@@ -241,7 +301,7 @@ fn crack_right(crack: Crack, crack_map: &CrackMap, is_hc: bool) -> Path {
 
     let logic: fn(&Progress) -> bool = if is_hc { |p| p.can_merge() } else { |p| p.are_cracks_open() && p.can_merge() };
 
-    Path::new(left, *Logic::new().normal(logic))
+    Path::crack(left, *Logic::new().normal(logic))
 }
 
 fn fast_travel_hyrule() -> Path {
@@ -256,3 +316,71 @@ fn fast_travel_lorule() -> Path {
 fn ghost(ghost: HintGhost) -> Check {
     Check::new(hint_ghost_name(&ghost), Logic::free(), Some(Randomizable::HintGhost(ghost)), None)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::filler::cracks::build_crack_map;
+    use crate::filler::dungeon_entrances::DungeonEntrance::{self, *};
+    use modinfo::settings::cracksanity::Cracksanity;
+    use modinfo::Settings;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn destinations(world: &WorldGraph, location: Location) -> Vec<Location> {
+        let node = world.get(&location).unwrap_or_else(|| panic!("World Graph missing Location: {:?}", location));
+        node.get_paths().iter().flatten().map(|path| path.get_destination()).collect()
+    }
+
+    /// Swapped Dungeon Entrances lead from each overworld entrance into the other dungeon's foyer, and back out of it
+    /// to where that entrance lets the player out, leaving the vanilla connections unused.
+    #[test]
+    fn connects_dungeon_entrances() {
+        let settings: Settings = serde_json::from_str("{}").unwrap();
+        let crack_map = build_crack_map(&settings, &mut StdRng::seed_from_u64(1)).unwrap();
+
+        let swaps = [(EasternPalace, TurtleRock), (DarkPalace, HouseOfGales)];
+        let mut dungeon_entrance_map: DungeonEntranceMap =
+            DungeonEntrance::ALL.into_iter().map(|entrance| (entrance, entrance)).collect();
+        for (a, b) in swaps {
+            dungeon_entrance_map.insert(a, b);
+            dungeon_entrance_map.insert(b, a);
+        }
+
+        let vanilla = build_world_graph(&crack_map, &Default::default(), &Default::default());
+        let world = build_world_graph(&crack_map, &dungeon_entrance_map, &Default::default());
+
+        for (entrance, &dungeon) in &dungeon_entrance_map {
+            let outside = destinations(&world, entrance.get_outside_location());
+            let foyer = destinations(&world, dungeon.get_foyer_location());
+
+            assert!(outside.contains(&dungeon.get_foyer_location()), "{} doesn't lead into {}", entrance, dungeon);
+            assert!(foyer.contains(&entrance.get_exit_location()), "{} doesn't lead out to {}", dungeon, entrance);
+
+            if *entrance == dungeon {
+                assert_eq!(outside, destinations(&vanilla, entrance.get_outside_location()));
+                assert_eq!(foyer, destinations(&vanilla, dungeon.get_foyer_location()));
+            } else {
+                assert!(!outside.contains(&entrance.get_foyer_location()));
+                assert!(!foyer.contains(&dungeon.get_exit_location()));
+            }
+        }
+    }
+
+    /// Crack paths follow the Crack Map one-way, so a Decoupled crack leads to its own destination rather than back
+    /// out of whichever crack leads into it.
+    #[test]
+    fn follows_decoupled_cracks() {
+        let settings = Settings { cracksanity: Cracksanity::Decoupled, ..serde_json::from_str("{}").unwrap() };
+        let crack_map = build_crack_map(&settings, &mut StdRng::seed_from_u64(1)).unwrap();
+
+        let mut one_way = false;
+        for (&crack, &destination) in &crack_map {
+            let (left, right) = destination.get_left_right_locations();
+            assert_eq!(crack_left(crack, &crack_map, false).get_destination(), right);
+            assert_eq!(crack_right(crack, &crack_map, false).get_destination(), left);
+            one_way |= crack_map[&destination] != crack;
+        }
+        assert!(one_way);
+    }
+}
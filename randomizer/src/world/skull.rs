@@ -1,3 +1,4 @@
+use crate::filler::bosses::BossArena;
 use crate::filler::check::Check;
 use crate::filler::filler_item::Goal;
 use crate::filler::location::Location::{self, *};
@@ -80,11 +81,11 @@ pub(crate) fn graph() -> HashMap<Location, LocationNode> {
             location(
                 "Skull Woods Boss Room",
                 vec![check!("[SW] Knucklemaster", regions::dungeons::skull::woods::SUBREGION, |p| p
-                    .can_defeat_knucklemaster())],
+                    .can_defeat_boss_in(BossArena::SkullWoods))],
                 vec![
-                    edge!(SkullWoodsBossHallway, |p| p.can_defeat_knucklemaster()),
+                    edge!(SkullWoodsBossHallway, |p| p.can_defeat_boss_in(BossArena::SkullWoods)),
                     edge!(SkullWoodsSeresGrove => {
-                        normal: |p| p.has_master_sword() || p.can_defeat_knucklemaster(),
+                        normal: |p| p.has_master_sword() || p.can_defeat_boss_in(BossArena::SkullWoods),
                         glitched: |p| p.has_tornado_rod(),
                     }),
                 ],
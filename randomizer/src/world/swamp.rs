@@ -1,3 +1,4 @@
+use crate::filler::bosses::BossArena;
 use crate::filler::check::Check;
 use crate::filler::filler_item::Goal;
 use crate::filler::location::Location::{self, *};
@@ -139,7 +140,7 @@ pub(crate) fn graph() -> HashMap<Location, LocationNode> {
                             && (p.progression_enemies() || p.has_bombs() || p.has_hammer())
                             && p.has_swamp_keys(4)
                             && p.has_swamp_big_key()
-                            && p.can_defeat_arrghus()
+                            && p.can_defeat_boss_in(BossArena::SwampPalace)
                     }),
                     None,
                     None,
@@ -1,3 +1,4 @@
+use crate::filler::bosses::BossArena;
 use crate::filler::check::Check;
 use crate::filler::filler_item::Goal;
 use crate::filler::location::Location::{self, *};
@@ -110,7 +111,11 @@ pub(crate) fn graph() -> HashMap<Location, LocationNode> {
         ),
         (
             ThievesBoss,
-            location("Thieves' Hideout Boss", None, vec![edge!(ThievesPostBoss, |p| p.can_merge() && p.can_attack())]),
+            location(
+                "Thieves' Hideout Boss",
+                None,
+                vec![edge!(ThievesPostBoss, |p| p.can_defeat_boss_in(BossArena::ThievesHideout))],
+            ),
         ),
         (
             ThievesPostBoss,
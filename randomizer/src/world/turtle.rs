@@ -1,3 +1,4 @@
+use crate::filler::bosses::BossArena;
 use crate::filler::check::Check;
 use crate::filler::filler_item::Goal;
 use crate::filler::location::Location::{self, *};
@@ -165,7 +166,14 @@ pub(crate) fn graph() -> HashMap<Location, LocationNode> {
             location(
                 "Turtle Rock Boss",
                 vec![],
-                vec![old_path(TurtleRockPostBoss, Some(|p| p.can_defeat_grinexx()), None, None, None, None)],
+                vec![old_path(
+                    TurtleRockPostBoss,
+                    Some(|p| p.can_defeat_boss_in(BossArena::TurtleRock)),
+                    None,
+                    None,
+                    None,
+                    None,
+                )],
             ),
         ),
         (
@@ -0,0 +1,364 @@
+//! Golden seed tests.
+//!
+//! Generates seeds for a matrix of settings and compares everything the RNG decides against the snapshots saved in
+//! `tests/golden`, so any change to the filler, hints or the order the RNG is consumed in gets flagged. No ROM needed.
+//! Every seed is also checked against the rules its settings promise, whether or not its snapshot changed.
+//!
+//! When a change to seed generation is intended, update the snapshots with:
+//!
+//! ```sh
+//! UPDATE_GOLDEN=1 cargo test -p randomizer --test golden
+//! ```
+
+use modinfo::settings::{
+    Accessibility, BossHunt, CompassShuffle, Cracksanity, DungeonEntrances, FillAlgorithm, InteriorShuffle, Keysanity,
+    LogicMode, Plando, ShopPrices, StartLocation, TowerFloorPool, WeatherVanes,
+};
+use modinfo::Settings;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use randomizer::{calculate_multiworld_seed_info, calculate_seed_info, SeedHash, SeedInfo};
+use serde_json::{Map, Value};
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::PathBuf;
+
+const SEEDS: [u32; 3] = [1, 2, 3];
+
+/// The dungeons found in Hyrule, every other one being in Lorule.
+const HYRULE_DUNGEONS: [&str; 5] =
+    ["Eastern Palace", "House of Gales", "Tower of Hera", "Hyrule Castle", "Desert Palace"];
+
+/// Items every seed where all locations are accessible must let the player find.
+const MAJOR_ITEMS: [&str; 17] = [
+    "Bow+", "Boomerang+", "Hookshot+", "Bombs+", "Fire Rod+", "Ice Rod+", "Hammer+", "Sand Rod+", "Tornado Rod+",
+    "Lamp+", "Net+", "Ravio's Bracelet+", "Glove+", "Flippers", "Pegasus Boots", "Bell", "Bow of Light",
+];
+
+/// The parts of the spoiler log decided by the RNG.
+const SNAPSHOT_KEYS: [&str; 13] = [
+    "start", "layout", "unreachable_checks", "hints", "crack_map", "weather_vane_map", "dungeon_entrances",
+    "interiors", "bosses", "trials_config", "treacherous_tower_floors", "required_bosses", "shop_prices",
+];
+
+#[test]
+fn example_preset() {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../presets/Example.json");
+    let preset = fs::read_to_string(path).expect("Example preset should exist");
+    let preset: SeedInfo = serde_json::from_reader(json_comments::StripComments::new(preset.as_bytes()))
+        .expect("Example preset should be valid");
+
+    check_golden_seeds("example", preset.settings);
+}
+
+#[test]
+fn defaults() {
+    check_golden_seeds("defaults", preset_defaults());
+}
+
+#[test]
+fn hard_cracksanity() {
+    // Seed 2 runs out of Prize checks for a Sage, see `filler::diagnostics`. Its snapshot records the failure.
+    check_golden_seeds(
+        "hard-cracksanity",
+        Settings {
+            logic_mode: LogicMode::Hard,
+            cracksanity: Cracksanity::AnyWorldPairs,
+            weather_vanes: WeatherVanes::Shuffled,
+            ..preset_defaults()
+        },
+    );
+}
+
+#[test]
+fn glitched_entrances() {
+    check_golden_seeds(
+        "glitched-entrances",
+        Settings {
+            logic_mode: LogicMode::Glitched,
+            dungeon_entrances: DungeonEntrances::CrossWorld,
+            interior_shuffle: InteriorShuffle::Coupled,
+            ..preset_defaults()
+        },
+    );
+}
+
+#[test]
+fn hell_decoupled() {
+    check_golden_seeds(
+        "hell-decoupled",
+        Settings {
+            logic_mode: LogicMode::Hell,
+            cracksanity: Cracksanity::Decoupled,
+            weather_vanes: WeatherVanes::Shuffled,
+            decouple_weather_vanes: true,
+            interior_shuffle: InteriorShuffle::Decoupled,
+            ..preset_defaults()
+        },
+    );
+}
+
+#[test]
+fn beatable_only() {
+    check_golden_seeds("beatable-only", Settings { accessibility: Accessibility::BeatableOnly, ..preset_defaults() });
+}
+
+#[test]
+fn boss_hunt() {
+    check_golden_seeds("boss-hunt", Settings { boss_hunt: BossHunt::EndGame, boss_hunt_count: 4, ..preset_defaults() });
+}
+
+#[test]
+fn forward_fill() {
+    check_golden_seeds("forward-fill", Settings { fill_algorithm: FillAlgorithm::ForwardFill, ..preset_defaults() });
+}
+
+#[test]
+fn progression_balancing() {
+    check_golden_seeds(
+        "progression-balancing",
+        Settings { fill_algorithm: FillAlgorithm::ProgressionBalancing, sphere_depth: 10, ..preset_defaults() },
+    );
+}
+
+#[test]
+fn plando() {
+    let pairs = |pairs: &[(&str, &str)]| pairs.iter().map(|&(a, b)| (a.to_owned(), b.to_owned())).collect();
+    check_golden_seeds(
+        "plando",
+        Settings {
+            cracksanity: Cracksanity::CrossWorldPairs,
+            weather_vanes: WeatherVanes::Shuffled,
+            plando: Plando {
+                items: pairs(&[
+                    ("Ravio's Shop (1)", "Bow+"),
+                    ("[EP] Prize", "Pendant of Courage"),
+                    ("[EP] (1F) Merge Chest", "Eastern Palace Small Key"),
+                ]),
+                cracks: pairs(&[("[HC] Crack", "Thieves' Town Crack")]),
+                weather_vanes: pairs(&[("Your House Weather Vane", "Dark Palace Weather Vane")]),
+            },
+            ..preset_defaults()
+        },
+    );
+}
+
+#[test]
+fn multiworld() {
+    check_golden_seeds("multiworld", Settings { players: 2, ..preset_defaults() });
+}
+
+#[test]
+fn shop_prices() {
+    check_golden_seeds("shop-prices", Settings { shop_prices: ShopPrices::Expensive, ..preset_defaults() });
+}
+
+#[test]
+fn traps() {
+    check_golden_seeds("traps", Settings { trap_frequency: 50, ..preset_defaults() });
+}
+
+#[test]
+fn start_location() {
+    check_golden_seeds("start-location", Settings { start_location: StartLocation::Random, ..preset_defaults() });
+}
+
+#[test]
+fn tower_floors() {
+    check_golden_seeds(
+        "tower-floors",
+        Settings {
+            treacherous_tower_floors: 8,
+            treacherous_tower_pool: TowerFloorPool::Hard,
+            treacherous_tower_excluded_floors: BTreeSet::from(["Advanced 2F".to_owned(), "Advanced 3F".to_owned()]),
+            ..preset_defaults()
+        },
+    );
+}
+
+#[test]
+fn boss_shuffle() {
+    check_golden_seeds("boss-shuffle", Settings { boss_shuffle: true, ..preset_defaults() });
+}
+
+/// Settings as a preset leaving every option to its default would give.
+fn preset_defaults() -> Settings {
+    serde_json::from_str("{}").expect("Settings should deserialize from defaults")
+}
+
+fn check_golden_seeds(name: &str, settings: Settings) {
+    let update = std::env::var_os("UPDATE_GOLDEN").is_some();
+    let mut mismatches = Vec::new();
+
+    for seed in SEEDS {
+        let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/golden").join(format!("{name}-{seed}.json"));
+        let snapshot = snapshot(seed, settings.clone());
+
+        if update {
+            fs::write(&path, snapshot).expect("Could not write snapshot");
+            continue;
+        }
+
+        let Ok(expected) = fs::read_to_string(&path) else {
+            mismatches.push(format!("{}: missing", path.display()));
+            continue;
+        };
+
+        let expected = expected.replace("\r\n", "\n");
+        if let Some((line, (expected, actual))) =
+            expected.lines().zip(snapshot.lines()).enumerate().find(|(_, (expected, actual))| expected != actual)
+        {
+            mismatches.push(format!(
+                "{}:{}\n    expected: {}\n    actual:   {}",
+                path.display(),
+                line + 1,
+                expected.trim(),
+                actual.trim()
+            ));
+        } else if expected.lines().count() != snapshot.lines().count() {
+            mismatches.push(format!("{}: length differs", path.display()));
+        }
+    }
+
+    assert!(
+        mismatches.is_empty(),
+        "Seeds differ from their golden snapshots. If intended, run `UPDATE_GOLDEN=1 cargo test -p randomizer --test \
+         golden`.\n\n{}\n",
+        mismatches.join("\n")
+    );
+}
+
+/// Generates a seed, checks it against its settings and serializes its snapshotted parts, or the error it failed with.
+fn snapshot(seed: u32, settings: Settings) -> String {
+    let hash = SeedHash::new(seed, &settings);
+    let rng = &mut StdRng::seed_from_u64(seed as u64);
+
+    let worlds = if settings.players > 1 {
+        calculate_multiworld_seed_info(seed, settings, hash, rng).map(|multiworld| multiworld.worlds)
+    } else {
+        calculate_seed_info(seed, settings, hash, rng).map(|seed_info| vec![seed_info])
+    };
+    let worlds = match worlds {
+        Ok(worlds) => worlds,
+        Err(err) => {
+            let error = Map::from_iter([("error".to_owned(), Value::String(err.into_inner().to_string()))]);
+            return serde_json::to_string_pretty(&error).expect("Snapshot should serialize") + "\n";
+        },
+    };
+
+    let mut snapshots = worlds.iter().map(|seed_info| {
+        let Value::Object(spoiler) = serde_json::to_value(seed_info).expect("Seed Info should serialize") else {
+            panic!("Seed Info should serialize to an object");
+        };
+        check_settings_honored(seed, seed_info, &spoiler);
+        (seed_info.player.to_string(), snapshot_keys(spoiler))
+    });
+    let snapshot = if worlds.len() > 1 {
+        snapshots.map(|(player, snapshot)| (player, Value::Object(snapshot))).collect()
+    } else {
+        snapshots.next().expect("A seed should have a world").1
+    };
+
+    serde_json::to_string_pretty(&snapshot).expect("Snapshot should serialize") + "\n"
+}
+
+fn snapshot_keys(mut spoiler: Map<String, Value>) -> Map<String, Value> {
+    SNAPSHOT_KEYS.iter().filter_map(|&key| Some((key.to_owned(), spoiler.remove(key)?))).collect()
+}
+
+/// Checks a world's spoiler log against the rules its settings promise.
+fn check_settings_honored(seed: u32, seed_info: &SeedInfo, spoiler: &Map<String, Value>) {
+    let settings = &seed_info.settings;
+    let placements = placements(&spoiler["layout"]);
+    let player = &seed_info.player;
+
+    // Dungeon items restricted to their own dungeon or world
+    for (region, check, item) in &placements {
+        let Some((dungeon, keysanity)) = [
+            (" Small Key", settings.small_keysanity),
+            (" Big Key", settings.big_keysanity),
+            (
+                " Compass",
+                match settings.compass_shuffle {
+                    CompassShuffle::Anywhere => Keysanity::Anywhere,
+                    _ => Keysanity::OwnDungeon,
+                },
+            ),
+        ]
+        .into_iter()
+        .find_map(|(suffix, keysanity)| Some((item.strip_suffix(suffix)?, keysanity))) else {
+            continue;
+        };
+
+        let allowed = match keysanity {
+            Keysanity::OwnDungeon => region == dungeon,
+            Keysanity::OwnWorld => is_hyrule(&spoiler["layout"], region) == is_hyrule(&spoiler["layout"], dungeon),
+            Keysanity::Anywhere => true,
+        };
+        assert!(allowed, "Seed {seed} ({player}): {item} was placed outside its area, at {check} in {region}");
+    }
+
+    // Plando placements
+    for (check, item) in &settings.plando.items {
+        let placed = placements.iter().find(|(_, placed_check, _)| placed_check == check).map(|(_, _, item)| item);
+        assert_eq!(placed, Some(item), "Seed {seed} ({player}): plando item at {check} wasn't honored");
+    }
+
+    // Shop prices
+    let range = match settings.shop_prices {
+        ShopPrices::Vanilla => None,
+        ShopPrices::Cheap => Some(10..=50),
+        ShopPrices::Expensive => Some(150..=300),
+        ShopPrices::Random => Some(settings.shop_price_min..=settings.shop_price_max),
+    };
+    match range {
+        None => assert!(seed_info.shop_prices.is_empty(), "Seed {seed} ({player}): vanilla shops were given prices"),
+        Some(range) => {
+            assert!(!seed_info.shop_prices.is_empty(), "Seed {seed} ({player}): shops kept their vanilla prices");
+            for (check, price) in &seed_info.shop_prices {
+                assert!(range.contains(price), "Seed {seed} ({player}): {check} costs {price}, outside {range:?}");
+            }
+        },
+    }
+
+    // Every major item found, in this world or another, when every location must be accessible
+    if settings.accessibility == Accessibility::AllLocations {
+        let playthrough = spoiler["metrics"]["playthrough"].as_object().expect("Playthrough should be an object");
+
+        assert!(
+            playthrough.values().any(|sphere| sphere["goals"]
+                .as_object()
+                .into_iter()
+                .flatten()
+                .any(|(_, goal)| goal == "Triforce")),
+            "Seed {seed} ({player}): the playthrough never reaches the Triforce"
+        );
+        for (_, check, item) in &placements {
+            let item = item
+                .split_once("'s ")
+                .filter(|(owner, _)| owner.starts_with("Player "))
+                .map_or(&**item, |(_, item)| item);
+            if MAJOR_ITEMS.contains(&item) {
+                let found = playthrough.values().any(|sphere| sphere["items"].get(check).is_some());
+                assert!(found, "Seed {seed} ({player}): {item} at {check} can't be found");
+            }
+        }
+    }
+}
+
+/// Every (region, check, item) of a spoiler log's layout.
+fn placements(layout: &Value) -> Vec<(String, String, String)> {
+    let regions = layout.as_object().into_iter().flat_map(|worlds| worlds.values()).filter_map(Value::as_object);
+    regions
+        .flat_map(|regions| regions.iter())
+        .flat_map(|(region, checks)| {
+            let checks = checks.as_object().into_iter().flatten();
+            checks.map(|(check, item)| (region.clone(), check.clone(), item.as_str().unwrap_or_default().to_owned()))
+        })
+        .collect()
+}
+
+/// Whether a region of a spoiler log's layout, or the dungeon it's named after, is in Hyrule.
+fn is_hyrule(layout: &Value, region: &str) -> bool {
+    layout["Hyrule"].get(region).is_some() || HYRULE_DUNGEONS.contains(&region)
+}
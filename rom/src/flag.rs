@@ -38,6 +38,18 @@ impl Flag {
         }
     }
 
+    /// Inverse of [`Self::into_pair`]. `None` for an unknown flag type.
+    pub fn from_pair(kind: u8, flag: u16) -> Option<Self> {
+        match kind {
+            0 => Some(Flag::Zero(flag)),
+            1 => Some(Flag::One(flag)),
+            2 => Some(Flag::Two(flag)),
+            3 => Some(Flag::Course(flag)),
+            4 => Some(Flag::Event(flag)),
+            _ => None,
+        }
+    }
+
     /// Gets the "true" flag using the game's internal lookup table
     pub fn get_true_flag(flag: u16) -> u16 {
         Self::TRUE_FLAG_LUT[flag as usize]
@@ -207,6 +207,26 @@ impl Stage {
         }
     }
 
+    /// Finds the UNQ and first argument of every Obj that spawns the given actor
+    pub fn find_objs_by_id(&self, id: i16) -> Vec<(u16, i32)> {
+        self.objs.iter().filter(|obj| obj.id == id).map(|obj| (obj.unq, obj.arg.0)).collect()
+    }
+
+    /// Finds the ID of every Spawn Point system object
+    pub fn find_spawn_points(&self) -> Vec<i32> {
+        self.system.iter().filter(|obj| obj.id == 7).map(|obj| obj.arg.0).collect()
+    }
+
+    /// Finds the UNQ and destination spawn of every loading zone that redirects the player into the given scene
+    pub fn find_redirects(&self, course: Course, scene: i32) -> Vec<(u16, i32)> {
+        self.objs
+            .iter()
+            .filter(|obj| Obj::LOADING_ZONE_IDS.contains(&obj.id))
+            .filter(|obj| obj.arg.10 == course as i32 && obj.arg.11 == scene - 1)
+            .map(|obj| (obj.unq, obj.arg.0))
+            .collect()
+    }
+
     /// Finds the lowest currently unused Objs UNQ
     pub fn find_objs_unq(&self) -> u16 {
         self.objs.iter().fold(0, |max_unq, obj| if obj.unq > max_unq { obj.unq } else { max_unq }) + 1
@@ -266,6 +286,9 @@ pub struct Obj {
 }
 
 impl Obj {
+    /// Actor IDs of the stage and room transitions, the loading zones that move the player into another scene
+    pub const LOADING_ZONE_IDS: [i16; 2] = [8, 24];
+
     /// Generates a new Spawn Point system object
     pub fn spawn_point(id: i32, clp: i16, ser: u16, unq: u16, translate: Vec3) -> Obj {
         Self {
@@ -1,11 +1,16 @@
 use log::{error, info, LevelFilter};
 use macros::fail;
+use modinfo::settings::Keysanity;
 use modinfo::settings::Keysy;
 use modinfo::settings::RaviosShop;
+use modinfo::settings::Trap;
 use modinfo::settings::TrialsDoor;
 use modinfo::settings::WeatherVanes;
-use modinfo::settings::{Cracks, NiceItems};
-use modinfo::settings::{Cracksanity, LogicMode, PedestalSetting, Settings};
+use modinfo::settings::{
+    Accessibility, BossHunt, Cracksanity, DungeonEntrances, FillAlgorithm, InteriorShuffle, LogicMode, MaiamaiRewards,
+    MaiamaiShuffle, PedestalSetting, Settings, ShopPrices, StartLocation, TowerFloorPool,
+};
+use modinfo::settings::{CompassShuffle, Cracks, NiceItems};
 use randomizer::filler::cracks::Crack;
 use randomizer::filler::filler_item::Item::*;
 use randomizer::filler::filler_item::Randomizable;
@@ -57,11 +62,20 @@ fn main() {
         version: String::from(VERSION),
         hash: SeedHash::new(seed, &settings),
         settings,
+        player: Default::default(),
+        received_items: Default::default(),
         full_exclusions: Default::default(),
+        start: Default::default(),
         treacherous_tower_floors: vec![],
+        required_bosses: vec![],
+        shop_prices: Default::default(),
         vane_map: get_plando_weather_vane_map(),
         crack_map: get_plando_crack_map(),
+        dungeon_entrance_map: Default::default(),
+        interior_map: Default::default(),
+        boss_map: Default::default(),
         layout: build_layout(),
+        unreachable_checks: Default::default(),
         metrics: Default::default(),
         text: Default::default(),
         hints: Default::default(),
@@ -180,38 +194,68 @@ fn plando_settings() -> Settings {
         dev_mode: true,
         lc_requirement: 7,
         yuganon_requirement: 7,
+        boss_hunt: BossHunt::Off,
+        boss_hunt_count: 10,
         ped_requirement: PedestalSetting::Standard,
         logic_mode: LogicMode::Normal,
+        accessibility: Accessibility::AllLocations,
+        fill_algorithm: FillAlgorithm::AssumedFill,
+        sphere_depth: 12,
+        players: 1,
         dark_rooms_lampless: false,
         dungeon_prize_shuffle: true,
+        prizes_anywhere: false,
         maiamai_limit: 50,
         maiamai_madness: false,
+        maiamai_shuffle: MaiamaiShuffle::All,
+        maiamai_rewards: MaiamaiRewards::Upgrades,
         nice_items: NiceItems::Off,
         super_items: false,
         lamp_and_net_as_weapons: false,
         cracks: Cracks::Open,
         cracksanity: Cracksanity::AnyWorldPairs,
+        dungeon_entrances: DungeonEntrances::Vanilla,
+        interior_shuffle: InteriorShuffle::Off,
+        boss_shuffle: false,
         weather_vanes: WeatherVanes::Hyrule,
+        decouple_weather_vanes: false,
         ravios_shop: RaviosShop::Open,
         bow_of_light_in_castle: false,
         no_progression_enemies: false,
         keysy: Keysy::Off,
+        small_keysanity: Keysanity::OwnDungeon,
+        big_keysanity: Keysanity::OwnDungeon,
+        compass_shuffle: CompassShuffle::OwnDungeon,
+        informational_compasses: false,
         progressive_bow_of_light: false,
         swordless_mode: false,
+        start_location: StartLocation::Vanilla,
         start_with_merge: true,
         start_with_pouch: true,
         bell_in_shop: false,
         sword_in_shop: false,
         boots_in_shop: false,
         assured_weapon: false,
+        shop_prices: ShopPrices::Vanilla,
+        shop_price_min: 0,
+        shop_price_max: 300,
         chest_size_matches_contents: true,
         minigames_excluded: false,
         skip_big_bomb_flower: true,
         trials_door: TrialsDoor::OneTrialRequired,
         treacherous_tower_floors: 5,
+        treacherous_tower_pool: TowerFloorPool::Mixed,
+        treacherous_tower_excluded_floors: Default::default(),
+        treacherous_tower_fixed_floors: vec![],
+        treacherous_tower_vanilla_reward: false,
+        starting_hearts: 3,
+        heart_cap: 20,
+        trap_frequency: 0,
+        trap_types: Trap::ALL.into(),
         purple_potion_bottles: true,
         night_mode: false,
         user_exclusions: BTreeSet::default(),
+        plando: Default::default(),
     }
 }
 
@@ -1,4 +1,5 @@
 use log::warn;
+use randomizer::filler::diagnostics::FillDiagnostics;
 use randomizer::SeedInfo;
 use {
     log::{error, info},
@@ -24,6 +25,10 @@ struct Opt {
 
     #[structopt(long)]
     no_spoiler: bool,
+
+    /// Write a report of every failed attempt to fill_diagnostics.json
+    #[structopt(long)]
+    diagnostics: bool,
 }
 
 /// THE LEGEND OF ZELDA: A LINK BETWEEN WORLDS RANDOMIZER
@@ -45,6 +50,8 @@ fn main() {
                 Full Error: {}\n", error);
     });
 
+    let mut diagnostics = opt.diagnostics.then(|| FillDiagnostics::new(preset_name.clone()));
+
     // Generate Seed in a retryable manner
     const MAX_RETRIES: u16 = 100;
     for x in 0..MAX_RETRIES {
@@ -57,7 +64,11 @@ fn main() {
         info!("Seed:                           {:0>10}", seed);
 
         let stopwatch = std::time::Instant::now();
-        match randomizer::generate_seed(seed, settings.clone(), &user_config, opt.no_patch, opt.no_spoiler) {
+        let result = randomizer::generate_seed(seed, settings.clone(), &user_config, opt.no_patch, opt.no_spoiler);
+        if let Some(diagnostics) = &mut diagnostics {
+            diagnostics.record(x + 1, seed, result.as_ref().err());
+        }
+        match result {
             Ok(_) => {
                 println!();
                 info!("Successfully Generated ALBWR Seed {} in {} seconds! :D", seed, stopwatch.elapsed().as_secs());
@@ -74,19 +85,30 @@ fn main() {
                         info!("A retryable error was encountered.\n");
                         seed = rand::random();
                     } else {
+                        finish_diagnostics(&mut diagnostics, &user_config);
                         fail!("Couldn't generate Seed: \"{}\" with the given settings.", seed);
                     }
                 } else {
+                    finish_diagnostics(&mut diagnostics, &user_config);
                     fail!("Too many retry attempts have failed. Aborting...");
                 }
             },
         }
     }
 
+    finish_diagnostics(&mut diagnostics, &user_config);
+
     println!();
     cli::pause();
 }
 
+/// Writes out the Fill Diagnostics report, if one was asked for.
+fn finish_diagnostics(diagnostics: &mut Option<FillDiagnostics>, user_config: &UserConfig) {
+    if let Some(diagnostics) = diagnostics {
+        diagnostics.finish(user_config).unwrap_or_else(|err| error!("Failed to write Fill Diagnostics: {:?}", err));
+    }
+}
+
 /// Get Settings, either from a preset or the CLI
 fn determine_settings(opt_preset: Option<String>, opt_seed: Option<u32>) -> (Option<String>, bool, SeedInfo) {
     if let Some(preset_name) = opt_preset {